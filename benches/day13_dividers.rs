@@ -0,0 +1,66 @@
+//! Compares the two ways of placing day 13's divider packets on randomly generated packets.
+//!
+//! Run with `cargo bench --bench day13_dividers`.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use advent_of_code_2022::bench::{Rng, Stats};
+use advent_of_code_2022::days::day13::{
+    divider_indices_by_counting, divider_indices_by_sorting, ListItem,
+};
+
+const ITERATIONS: usize = 20;
+
+const MAX_DEPTH: usize = 4;
+
+fn random_packet(rng: &mut Rng, depth: usize) -> ListItem {
+    // The top level is always a list
+    if depth == MAX_DEPTH || (depth > 0 && rng.next(2) == 0) {
+        return ListItem::Int(rng.next(11) as u32);
+    }
+
+    let len = rng.next(5);
+    let items = (0..len).map(|_| random_packet(rng, depth + 1)).collect();
+    ListItem::List(items)
+}
+
+fn time(mut f: impl FnMut() -> Vec<usize>) -> Stats {
+    let samples: Vec<Duration> = (0..ITERATIONS)
+        .map(|_| {
+            let start = Instant::now();
+            black_box(f());
+            start.elapsed()
+        })
+        .collect();
+    Stats::from_samples(&samples).unwrap()
+}
+
+fn main() {
+    let dividers: Vec<ListItem> = ["[[2]]", "[[6]]"]
+        .iter()
+        .map(|divider| divider.parse().unwrap())
+        .collect();
+
+    let mut rng = Rng::new(0x2022_1213);
+    for num_packets in [100, 1_000, 10_000, 100_000] {
+        let packets: Vec<_> = (0..num_packets)
+            .map(|_| random_packet(&mut rng, 0))
+            .collect();
+
+        assert_eq!(
+            divider_indices_by_sorting(&packets, &dividers),
+            divider_indices_by_counting(&packets, &dividers)
+        );
+
+        println!("---------- {num_packets} packets ----------");
+        println!(
+            "sort   {}",
+            time(|| divider_indices_by_sorting(&packets, &dividers))
+        );
+        println!(
+            "count  {}",
+            time(|| divider_indices_by_counting(&packets, &dividers))
+        );
+    }
+}
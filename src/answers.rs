@@ -0,0 +1,210 @@
+//! Recorded puzzle answers used to check for regressions on real inputs. The file is a small
+//! TOML subset:
+//!
+//! ```text
+//! # comments and blank lines are ignored
+//! [day1]
+//! part1 = "70374"
+//! part2 = "204610"
+//! ```
+//!
+//! Values are double-quoted strings supporting the `\n`, `\"` and `\\` escapes.
+
+use std::collections::HashMap;
+use std::fmt::{self, Display};
+use std::path::PathBuf;
+
+use crate::error::{self, ParseError};
+use crate::input;
+use crate::solution::Part;
+
+const ANSWERS_FILE_NAME: &str = "answers.toml";
+
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Answers {
+    answers: HashMap<(u32, Part), String>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum CheckResult {
+    Pass,
+    Fail { expected: String },
+    Missing,
+}
+
+impl Display for CheckResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Pass => write!(f, "pass"),
+            Self::Fail { expected } => write!(f, "FAIL (expected {expected})"),
+            Self::Missing => write!(f, "missing"),
+        }
+    }
+}
+
+impl Answers {
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut answers = HashMap::new();
+        let mut day: Option<u32> = None;
+
+        for (line_number, line) in error::numbered_lines(input) {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            if let Some(header) = line.strip_prefix('[') {
+                let header = header.strip_suffix(']').ok_or_else(|| {
+                    ParseError::new(line_number, 1, "section header should end with ']'")
+                })?;
+                let n = header.strip_prefix("day").ok_or_else(|| {
+                    ParseError::at(
+                        line_number,
+                        line,
+                        header,
+                        format!("section should be named dayN, was '{header}'"),
+                    )
+                })?;
+                day = Some(error::parse_number(line_number, line, n, "day")?);
+                continue;
+            }
+
+            let Some(day) = day else {
+                return Err(ParseError::new(
+                    line_number,
+                    1,
+                    "answer should appear after a [dayN] section header",
+                ));
+            };
+
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| ParseError::new(line_number, 1, "expected 'key = \"value\"'"))?;
+            let key = key.trim();
+            let value = value.trim();
+
+            let part = match key {
+                "part1" => Part::One,
+                "part2" => Part::Two,
+                _ => {
+                    return Err(ParseError::at(
+                        line_number,
+                        line,
+                        key,
+                        format!("key should be part1 or part2, was '{key}'"),
+                    ))
+                }
+            };
+
+            let value = parse_string(value)
+                .map_err(|message| ParseError::at(line_number, line, value, message))?;
+            answers.insert((day, part), value);
+        }
+
+        Ok(Self { answers })
+    }
+
+    pub fn get(&self, day: u32, part: Part) -> Option<&str> {
+        self.answers.get(&(day, part)).map(String::as_str)
+    }
+
+    pub fn check(&self, day: u32, part: Part, answer: &str) -> CheckResult {
+        match self.get(day, part) {
+            Some(expected) if expected == answer => CheckResult::Pass,
+            Some(expected) => CheckResult::Fail {
+                expected: String::from(expected),
+            },
+            None => CheckResult::Missing,
+        }
+    }
+}
+
+// answers.toml in the input directory
+pub fn answers_path() -> PathBuf {
+    input::input_dir().join(ANSWERS_FILE_NAME)
+}
+
+fn parse_string(s: &str) -> Result<String, String> {
+    let inner = s
+        .strip_prefix('"')
+        .and_then(|s| s.strip_suffix('"'))
+        .filter(|_| s.len() >= 2)
+        .ok_or_else(|| format!("value should be a double-quoted string, was {s}"))?;
+
+    let mut result = String::with_capacity(inner.len());
+    let mut chars = inner.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next() {
+                Some('n') => result.push('\n'),
+                Some('"') => result.push('"'),
+                Some('\\') => result.push('\\'),
+                Some(c) => return Err(format!("unsupported escape sequence: \\{c}")),
+                None => return Err(String::from("string should not end with a backslash")),
+            },
+            '"' => return Err(String::from("quotes inside a value should be escaped")),
+            _ => result.push(c),
+        }
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE_ANSWERS: &str = "\
+# Sample answers
+[day1]
+part1 = \"24000\"
+part2 = \"45000\"
+
+[day10]
+part2 = \"##..\\n#..#\"
+";
+
+    #[test]
+    fn test_parse() {
+        let answers = Answers::parse(SAMPLE_ANSWERS).unwrap();
+        assert_eq!(Some("24000"), answers.get(1, Part::One));
+        assert_eq!(Some("45000"), answers.get(1, Part::Two));
+        assert_eq!(None, answers.get(10, Part::One));
+        assert_eq!(Some("##..\n#..#"), answers.get(10, Part::Two));
+    }
+
+    #[test]
+    fn test_check() {
+        let answers = Answers::parse(SAMPLE_ANSWERS).unwrap();
+        assert_eq!(CheckResult::Pass, answers.check(1, Part::One, "24000"));
+        assert_eq!(
+            CheckResult::Fail {
+                expected: String::from("45000")
+            },
+            answers.check(1, Part::Two, "44000")
+        );
+        assert_eq!(CheckResult::Missing, answers.check(2, Part::One, "15"));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            Err(ParseError::new(
+                1,
+                1,
+                "answer should appear after a [dayN] section header"
+            )),
+            Answers::parse("part1 = \"1\"")
+        );
+        assert_eq!(
+            Err(ParseError::new(
+                2,
+                1,
+                "key should be part1 or part2, was 'part3'"
+            )),
+            Answers::parse("[day1]\npart3 = \"1\"")
+        );
+        assert!(Answers::parse("[day1]\npart1 = 1").is_err());
+        assert!(Answers::parse("[dayone]").is_err());
+    }
+}
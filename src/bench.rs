@@ -0,0 +1,100 @@
+//! Summary statistics for repeated timings, used by `aoc bench` and the benchmarks in `benches`,
+//! and a random number generator for making up inputs to time or test against
+
+use std::fmt::{self, Display};
+use std::time::Duration;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+}
+
+impl Stats {
+    // Returns None if there are no samples
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let mid = sorted.len() / 2;
+        let median = if sorted.len().is_multiple_of(2) {
+            (sorted[mid - 1] + sorted[mid]) / 2
+        } else {
+            sorted[mid]
+        };
+
+        let total: Duration = sorted.iter().sum();
+        let mean = total / sorted.len() as u32;
+
+        Some(Self {
+            min: sorted[0],
+            median,
+            mean,
+        })
+    }
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "min {:>10.2?}  median {:>10.2?}  mean {:>10.2?}",
+            self.min, self.median, self.mean
+        )
+    }
+}
+
+// Small xorshift generator, so that generated inputs are the same on every run without any
+// dependencies
+pub struct Rng(u64);
+
+impl Rng {
+    // The seed should not be 0, which the generator never leaves
+    pub fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    // A number in 0..bound
+    pub fn next(&mut self, bound: u64) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0 % bound
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn millis(values: &[u64]) -> Vec<Duration> {
+        values.iter().copied().map(Duration::from_millis).collect()
+    }
+
+    #[test]
+    fn test_odd_samples() {
+        assert_eq!(
+            Some(Stats {
+                min: Duration::from_millis(1),
+                median: Duration::from_millis(3),
+                mean: Duration::from_millis(4),
+            }),
+            Stats::from_samples(&millis(&[3, 8, 1]))
+        );
+    }
+
+    #[test]
+    fn test_even_samples() {
+        let stats = Stats::from_samples(&millis(&[4, 1, 2, 9])).unwrap();
+        assert_eq!(Duration::from_millis(1), stats.min);
+        assert_eq!(Duration::from_millis(3), stats.median);
+        assert_eq!(Duration::from_millis(4), stats.mean);
+
+        assert_eq!(None, Stats::from_samples(&[]));
+    }
+}
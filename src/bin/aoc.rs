@@ -0,0 +1,507 @@
+//! Runs any combination of days from a single executable, e.g. `aoc run 1..=25` or
+//! `aoc run --part 2 16`. With `--check`, answers are compared against the answers file in the
+//! input directory instead of being printed. `aoc bench` solves each day repeatedly and reports
+//! timing statistics. Days with extra settings take them as `--option name=value`.
+
+use std::time::Duration;
+use std::{env, fs, process};
+
+use advent_of_code_2022::answers::{self, Answers, CheckResult};
+use advent_of_code_2022::bench::Stats;
+use advent_of_code_2022::days;
+use advent_of_code_2022::json;
+use advent_of_code_2022::solution::{DayResult, Options, Part, SolveError};
+use advent_of_code_2022::InputSource;
+
+const USAGE: &str = "usage: aoc run [--check] [--time] [--json] [--part <1|2>] [--input <path|->]
+               [--option <name=value>]... <days>...
+       aoc bench [--iterations <n>] [--part <1|2>] [--input <path|->]
+                 [--option <name=value>]... <days>...
+where each of <days> is a day, start..=end, or start..end";
+
+const DEFAULT_BENCH_ITERATIONS: usize = 10;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum Command {
+    Run,
+    Bench { iterations: usize },
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct RunArgs {
+    command: Command,
+    days: Vec<u32>,
+    parts: Vec<Part>,
+    // Only allowed when running a single day; defaults to that day's file in the input directory
+    input: Option<InputSource>,
+    // Day-specific settings, only allowed when running a single day
+    options: Options,
+    check: bool,
+    time: bool,
+    // Print one JSON object per day instead of plain answers
+    json: bool,
+}
+
+fn parse_args(args: &[String]) -> Result<RunArgs, String> {
+    let (command, rest) = args.split_first().ok_or("missing command")?;
+    let mut command = match command.as_str() {
+        "run" => Command::Run,
+        "bench" => Command::Bench {
+            iterations: DEFAULT_BENCH_ITERATIONS,
+        },
+        _ => return Err(format!("unknown command: {command}")),
+    };
+
+    let mut days: Vec<u32> = Vec::new();
+    let mut parts = Part::ALL.to_vec();
+    let mut input: Option<InputSource> = None;
+    let mut options = Options::new();
+    let mut check = false;
+    let mut time = false;
+    let mut json = false;
+
+    let mut iter = rest.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--part" => {
+                let part = iter.next().ok_or("--part requires a value")?;
+                parts = vec![part.parse()?];
+            }
+            "--check" => check = true,
+            "--time" => time = true,
+            "--json" => json = true,
+            "--iterations" => {
+                let Command::Bench { iterations } = &mut command else {
+                    return Err(String::from("--iterations is only valid with bench"));
+                };
+                let n = iter.next().ok_or("--iterations requires a value")?;
+                *iterations = match n.parse() {
+                    Ok(n) if n > 0 => n,
+                    _ => return Err(format!("iterations should be a positive integer, was {n}")),
+                };
+            }
+            "--input" => {
+                let arg = iter.next().ok_or("--input requires a value")?;
+                input = Some(InputSource::from_arg(arg));
+            }
+            "--option" => {
+                let arg = iter.next().ok_or("--option requires a value")?;
+                options.insert_arg(arg)?;
+            }
+            _ => days.extend(parse_days(arg)?),
+        }
+    }
+
+    if days.is_empty() {
+        return Err(String::from("no days specified"));
+    }
+
+    if input.is_some() && days.len() > 1 {
+        return Err(String::from(
+            "--input can only be used when running a single day",
+        ));
+    }
+
+    if !options.is_empty() && days.len() > 1 {
+        return Err(String::from(
+            "--option can only be used when running a single day",
+        ));
+    }
+
+    if command != Command::Run && (check || time || json) {
+        return Err(String::from(
+            "--check, --time, and --json are only valid with run",
+        ));
+    }
+
+    if json && (check || time) {
+        return Err(String::from(
+            "--json cannot be combined with --check or --time",
+        ));
+    }
+
+    Ok(RunArgs {
+        command,
+        days,
+        parts,
+        input,
+        options,
+        check,
+        time,
+        json,
+    })
+}
+
+// Parse either a single day or a range of days in Rust range syntax
+fn parse_days(s: &str) -> Result<Vec<u32>, String> {
+    let parse_number = |day: &str| -> Result<u32, String> {
+        day.parse()
+            .map_err(|_| format!("day should be an integer: {day}"))
+    };
+    let check_day = |day: u32| -> Result<u32, String> {
+        if days::get(day).is_none() {
+            return Err(format!("no solution registered for day {day}"));
+        }
+        Ok(day)
+    };
+    let parse_day = |day: &str| check_day(parse_number(day)?);
+
+    if let Some((start, end)) = s.split_once("..=") {
+        Ok((parse_day(start)?..=parse_day(end)?).collect())
+    } else if let Some((start, end)) = s.split_once("..") {
+        let start = parse_day(start)?;
+        let end = parse_number(end)?;
+        // The end is excluded, so the last day actually run is the one that has to exist
+        if end > start {
+            check_day(end - 1)?;
+        }
+        Ok((start..end).collect())
+    } else {
+        Ok(vec![parse_day(s)?])
+    }
+}
+
+fn read_day_input(source: &InputSource) -> Result<String, String> {
+    advent_of_code_2022::read_input(source)
+        .map_err(|err| format!("unable to read {}: {err}", source.describe()))
+}
+
+fn describe_error(err: SolveError, source: &InputSource) -> String {
+    match err {
+        SolveError::Parse(err) => format!("unable to parse {}: {err}", source.describe()),
+        SolveError::InvalidOption(message) => message,
+    }
+}
+
+fn solve_day(
+    day: u32,
+    source: &InputSource,
+    parts: &[Part],
+    options: &Options,
+) -> Result<DayResult, String> {
+    let solver = days::get(day).expect("days should have been validated while parsing args");
+    let input = read_day_input(source)?;
+    solver
+        .solve(&input, parts, options)
+        .map_err(|err| describe_error(err, source))
+}
+
+// Returns false if the day could not be run
+fn run_day_json(day: u32, source: &InputSource, parts: &[Part], options: &Options) -> bool {
+    let result = solve_day(day, source, parts, options);
+    println!("{}", json::day_result(day, &result));
+    result.is_ok()
+}
+
+// Returns false if the day could not be run or if any answer failed its check
+fn run_day(
+    day: u32,
+    source: &InputSource,
+    parts: &[Part],
+    options: &Options,
+    answers: Option<&Answers>,
+    time: bool,
+) -> bool {
+    println!("---------- DAY {day} ----------");
+
+    let result = match solve_day(day, source, parts, options) {
+        Ok(result) => result,
+        Err(err) => {
+            eprintln!("{err}");
+            return false;
+        }
+    };
+
+    let mut all_passed = true;
+    for part_result in &result.parts {
+        let answer = &part_result.answer;
+        match answers {
+            Some(answers) => {
+                let check = answers.check(day, part_result.part, answer);
+                all_passed &= !matches!(check, CheckResult::Fail { .. });
+                println!("part {}: {check}", part_result.part);
+                if !matches!(check, CheckResult::Pass) {
+                    println!("got {answer}");
+                }
+            }
+            None => println!("{answer}"),
+        }
+    }
+
+    if time {
+        print_timings(&result);
+    }
+
+    all_passed
+}
+
+fn print_timings(result: &DayResult) {
+    let mut timings = vec![format!("parse {:.2?}", result.parse_elapsed)];
+    timings.extend(
+        result
+            .parts
+            .iter()
+            .map(|part_result| format!("part {} {:.2?}", part_result.part, part_result.elapsed)),
+    );
+    println!("({})", timings.join(", "));
+}
+
+// Solve the day iterations times and report min/median/mean for parsing and each part
+fn bench_day(
+    day: u32,
+    source: &InputSource,
+    parts: &[Part],
+    options: &Options,
+    iterations: usize,
+) -> bool {
+    let solver = days::get(day).expect("days should have been validated while parsing args");
+
+    println!("---------- DAY {day} ----------");
+
+    let input = match read_day_input(source) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("{err}");
+            return false;
+        }
+    };
+
+    let mut parse_samples: Vec<Duration> = Vec::with_capacity(iterations);
+    let mut part_samples: Vec<(Part, Vec<Duration>)> = Vec::new();
+    for _ in 0..iterations {
+        let result = match solver.solve(&input, parts, options) {
+            Ok(result) => result,
+            Err(err) => {
+                eprintln!("{}", describe_error(err, source));
+                return false;
+            }
+        };
+
+        parse_samples.push(result.parse_elapsed);
+        for part_result in result.parts {
+            match part_samples
+                .iter_mut()
+                .find(|(part, _)| *part == part_result.part)
+            {
+                Some((_, samples)) => samples.push(part_result.elapsed),
+                None => part_samples.push((part_result.part, vec![part_result.elapsed])),
+            }
+        }
+    }
+
+    if let Some(stats) = Stats::from_samples(&parse_samples) {
+        println!("parse   {stats}");
+    }
+    for (part, samples) in &part_samples {
+        if let Some(stats) = Stats::from_samples(samples) {
+            println!("part {part}  {stats}");
+        }
+    }
+
+    true
+}
+
+fn load_answers() -> Result<Answers, String> {
+    let path = answers::answers_path();
+    let contents = fs::read_to_string(&path)
+        .map_err(|err| format!("unable to read {}: {err}", path.display()))?;
+    Answers::parse(&contents).map_err(|err| format!("unable to parse {}: {err}", path.display()))
+}
+
+fn main() {
+    let args: Vec<_> = env::args().skip(1).collect();
+    let RunArgs {
+        command,
+        days,
+        parts,
+        input,
+        options,
+        check,
+        time,
+        json,
+    } = match parse_args(&args) {
+        Ok(run_args) => run_args,
+        Err(err) => {
+            eprintln!("{err}");
+            eprintln!("{USAGE}");
+            process::exit(2);
+        }
+    };
+
+    let answers = if check {
+        match load_answers() {
+            Ok(answers) => Some(answers),
+            Err(err) => {
+                eprintln!("{err}");
+                process::exit(2);
+            }
+        }
+    } else {
+        None
+    };
+
+    let mut all_succeeded = true;
+    for day in days {
+        let source = input.clone().unwrap_or(InputSource::Day(day));
+        all_succeeded &= match command {
+            Command::Run if json => run_day_json(day, &source, &parts, &options),
+            Command::Run => run_day(day, &source, &parts, &options, answers.as_ref(), time),
+            Command::Bench { iterations } => bench_day(day, &source, &parts, &options, iterations),
+        };
+    }
+
+    if !all_succeeded {
+        process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(s: &str) -> Vec<String> {
+        s.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn test_parse_single_day() {
+        assert_eq!(
+            Ok(RunArgs {
+                command: Command::Run,
+                days: vec![17],
+                parts: vec![Part::One, Part::Two],
+                input: None,
+                options: Options::new(),
+                check: false,
+                time: false,
+                json: false,
+            }),
+            parse_args(&args("run 17"))
+        );
+    }
+
+    #[test]
+    fn test_parse_ranges() {
+        assert_eq!(Ok((1..=25).collect()), parse_days("1..=25"));
+        assert_eq!(Ok(vec![3, 4]), parse_days("3..5"));
+        assert!(parse_days("0..=3").is_err());
+        assert_eq!(Ok((1..=25).collect()), parse_days("1..26"));
+        assert!(parse_days("1..27").is_err());
+        assert!(parse_days("26").is_err());
+    }
+
+    #[test]
+    fn test_parse_part() {
+        assert_eq!(
+            Ok(RunArgs {
+                command: Command::Run,
+                days: vec![16],
+                parts: vec![Part::Two],
+                input: None,
+                options: Options::new(),
+                check: false,
+                time: false,
+                json: false,
+            }),
+            parse_args(&args("run --part 2 16"))
+        );
+        assert!(parse_args(&args("run --part 3 16")).is_err());
+        assert!(parse_args(&args("run --part")).is_err());
+        assert!(parse_args(&args("walk 16")).is_err());
+    }
+
+    #[test]
+    fn test_parse_input() {
+        assert_eq!(
+            Ok(RunArgs {
+                command: Command::Run,
+                days: vec![5],
+                parts: vec![Part::One, Part::Two],
+                input: Some(InputSource::Stdin),
+                options: Options::new(),
+                check: false,
+                time: false,
+                json: false,
+            }),
+            parse_args(&args("run --input - 5"))
+        );
+        assert!(parse_args(&args("run --input - 1..=2")).is_err());
+    }
+
+    #[test]
+    fn test_parse_check() {
+        assert_eq!(
+            Ok(RunArgs {
+                command: Command::Run,
+                days: (1..=25).collect(),
+                parts: vec![Part::One, Part::Two],
+                input: None,
+                options: Options::new(),
+                check: true,
+                time: false,
+                json: false,
+            }),
+            parse_args(&args("run --check 1..=25"))
+        );
+    }
+
+    #[test]
+    fn test_parse_bench() {
+        assert_eq!(
+            Ok(RunArgs {
+                command: Command::Bench { iterations: 50 },
+                days: vec![16, 17],
+                parts: vec![Part::One, Part::Two],
+                input: None,
+                options: Options::new(),
+                check: false,
+                time: false,
+                json: false,
+            }),
+            parse_args(&args("bench --iterations 50 16..=17"))
+        );
+        assert!(parse_args(&args("bench --iterations 0 16")).is_err());
+        assert!(parse_args(&args("run --iterations 5 16")).is_err());
+        assert!(parse_args(&args("bench --check 16")).is_err());
+    }
+
+    #[test]
+    fn test_parse_json() {
+        assert_eq!(
+            Ok(RunArgs {
+                command: Command::Run,
+                days: vec![10],
+                parts: vec![Part::One, Part::Two],
+                input: None,
+                options: Options::new(),
+                check: false,
+                time: false,
+                json: true,
+            }),
+            parse_args(&args("run --json 10"))
+        );
+        assert!(parse_args(&args("run --json --check 10")).is_err());
+        assert!(parse_args(&args("bench --json 10")).is_err());
+    }
+
+    #[test]
+    fn test_parse_option() {
+        let mut options = Options::new();
+        options.insert("crane", "capacity:3");
+        assert_eq!(
+            Ok(RunArgs {
+                command: Command::Run,
+                days: vec![5],
+                parts: vec![Part::One, Part::Two],
+                input: None,
+                options,
+                check: false,
+                time: false,
+                json: false,
+            }),
+            parse_args(&args("run --option crane=capacity:3 5"))
+        );
+        assert!(parse_args(&args("run --option crane 5")).is_err());
+        assert!(parse_args(&args("run --option crane=bulk 5..=6")).is_err());
+    }
+}
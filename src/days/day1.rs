@@ -0,0 +1,90 @@
+//! Day 1: Calorie Counting
+//!
+//! <https://adventofcode.com/2022/day/1>
+
+use crate::error::{self, ParseError};
+use crate::solution::Solution;
+
+fn solve(sums: &[i32]) -> i32 {
+    sums.iter()
+        .copied()
+        .max()
+        .expect("list should not be empty")
+}
+
+fn solve_part_2(sums: &[i32]) -> i32 {
+    let mut sums = sums.to_vec();
+
+    sums.sort_by(|a, b| a.cmp(b).reverse());
+
+    sums[..3].iter().sum()
+}
+
+fn parse_input(input: &str) -> Result<Vec<i32>, ParseError> {
+    let sums: Vec<_> = error::numbered_blocks(input)?
+        .iter()
+        .map(|block| parse_and_sum(block))
+        .collect::<Result<_, _>>()?;
+
+    // Part 2 adds up the top three
+    if sums.len() < 3 {
+        return Err(ParseError::new(1, 1, "there should be at least 3 elves"));
+    }
+
+    Ok(sums)
+}
+
+fn parse_and_sum(slice: &[(usize, &str)]) -> Result<i32, ParseError> {
+    slice
+        .iter()
+        .map(|&(line_number, s)| error::parse_number::<i32>(line_number, s, s, "calories"))
+        .sum()
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u32 = 1;
+
+    type Input = Vec<i32>;
+    type Output1 = i32;
+    type Output2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Output1 {
+        solve(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Output2 {
+        solve_part_2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE_INPUT: &str = include_str!("sample_input/sample1.txt");
+
+    #[test]
+    fn test_sample_input_part_1() {
+        assert_eq!(24000, solve(&parse_input(SAMPLE_INPUT).unwrap()));
+    }
+
+    #[test]
+    fn test_sample_input_part_2() {
+        assert_eq!(45000, solve_part_2(&parse_input(SAMPLE_INPUT).unwrap()));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            Err(ParseError::new(1, 1, "there should be at least 3 elves")),
+            parse_input("100\n200\n\n300")
+        );
+        assert!(parse_input("").is_err());
+    }
+}
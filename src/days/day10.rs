@@ -0,0 +1,424 @@
+//! Day 10: Cathode-Ray Tube
+//!
+//! <https://adventofcode.com/2022/day/10>
+
+use std::collections::BTreeSet;
+use std::fmt::{self, Display};
+
+use crate::error::{self, ParseError};
+use crate::ocr;
+use crate::solution::Solution;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Register {
+    X,
+    Y,
+}
+
+impl Register {
+    pub const ALL: [Self; 2] = [Self::X, Self::Y];
+
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "x" => Some(Self::X),
+            "y" => Some(Self::Y),
+            _ => None,
+        }
+    }
+
+    fn name(self) -> char {
+        match self {
+            Self::X => 'x',
+            Self::Y => 'y',
+        }
+    }
+}
+
+pub type Registers = [i32; Register::ALL.len()];
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Instruction {
+    Noop,
+    // addx / addy
+    Add(Register, i32),
+    // Relative jump
+    Jmp(i32),
+    // Relative jump if the register is not zero
+    Jnz(Register, i32),
+}
+
+impl Instruction {
+    fn from_line(line_number: usize, line: &str) -> Result<Self, ParseError> {
+        let parse_register = |name: &str| {
+            Register::from_name(name).ok_or_else(|| {
+                ParseError::at(
+                    line_number,
+                    line,
+                    name,
+                    format!("register should be x or y, was '{name}'"),
+                )
+            })
+        };
+        let parse_operand =
+            |operand: &str| error::parse_number(line_number, line, operand, "operand");
+
+        let split: Vec<_> = line.split(' ').collect();
+        match split.as_slice() {
+            ["noop"] => Ok(Self::Noop),
+            [op, operand] if op.len() == 4 && op.starts_with("add") => Ok(Self::Add(
+                parse_register(&op[3..])?,
+                parse_operand(operand)?,
+            )),
+            ["jmp", offset] => Ok(Self::Jmp(parse_operand(offset)?)),
+            ["jnz", register, offset] => {
+                Ok(Self::Jnz(parse_register(register)?, parse_operand(offset)?))
+            }
+            _ => Err(ParseError::new(
+                line_number,
+                1,
+                format!("unrecognized instruction: '{line}'"),
+            )),
+        }
+    }
+
+    // How many cycles the instruction takes to complete
+    pub fn cycles(self) -> usize {
+        match self {
+            Self::Noop | Self::Jmp(_) => 1,
+            Self::Add(..) | Self::Jnz(..) => 2,
+        }
+    }
+}
+
+impl Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Noop => write!(f, "noop"),
+            Self::Add(register, operand) => write!(f, "add{} {operand}", register.name()),
+            Self::Jmp(offset) => write!(f, "jmp {offset}"),
+            Self::Jnz(register, offset) => write!(f, "jnz {} {offset}", register.name()),
+        }
+    }
+}
+
+// Register values during a single cycle, before the instruction finishing in that cycle applies
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct CycleState {
+    pub cycle: usize,
+    pub pc: usize,
+    pub registers: Registers,
+}
+
+impl CycleState {
+    pub fn register(&self, register: Register) -> i32 {
+        self.registers[register as usize]
+    }
+}
+
+pub struct Cpu<'a> {
+    program: &'a [Instruction],
+    registers: Registers,
+    pc: usize,
+    // The next cycle to execute, starting from 1
+    cycle: usize,
+    // Cycles left before the instruction at pc completes, or 0 if it hasn't started
+    remaining_cycles: usize,
+    breakpoints: BTreeSet<usize>,
+    trace: Option<Vec<CycleState>>,
+}
+
+impl<'a> Cpu<'a> {
+    pub fn new(program: &'a [Instruction]) -> Self {
+        Self {
+            program,
+            registers: [INITIAL_REGISTER_VALUE; Register::ALL.len()],
+            pc: 0,
+            cycle: 1,
+            remaining_cycles: 0,
+            breakpoints: BTreeSet::new(),
+            trace: None,
+        }
+    }
+
+    pub fn is_halted(&self) -> bool {
+        self.pc >= self.program.len()
+    }
+
+    pub fn register(&self, register: Register) -> i32 {
+        self.registers[register as usize]
+    }
+
+    pub fn add_breakpoint(&mut self, cycle: usize) {
+        self.breakpoints.insert(cycle);
+    }
+
+    pub fn remove_breakpoint(&mut self, cycle: usize) {
+        self.breakpoints.remove(&cycle);
+    }
+
+    // Record every cycle from now on so that it can be dumped with dump_trace
+    pub fn enable_trace(&mut self) {
+        self.trace.get_or_insert_with(Vec::new);
+    }
+
+    // Execute a single cycle, returning the state during that cycle or None if the program halted
+    pub fn step(&mut self) -> Option<CycleState> {
+        if self.is_halted() {
+            return None;
+        }
+
+        let instruction = self.program[self.pc];
+        if self.remaining_cycles == 0 {
+            self.remaining_cycles = instruction.cycles();
+        }
+
+        let state = CycleState {
+            cycle: self.cycle,
+            pc: self.pc,
+            registers: self.registers,
+        };
+        if let Some(trace) = &mut self.trace {
+            trace.push(state);
+        }
+
+        self.cycle += 1;
+        self.remaining_cycles -= 1;
+        if self.remaining_cycles == 0 {
+            self.execute(instruction);
+        }
+
+        Some(state)
+    }
+
+    // Run until reaching a breakpoint cycle and return the state during it, or None on halt
+    pub fn run_until_breakpoint(&mut self) -> Option<CycleState> {
+        while let Some(state) = self.step() {
+            if self.breakpoints.contains(&state.cycle) {
+                return Some(state);
+            }
+        }
+        None
+    }
+
+    fn execute(&mut self, instruction: Instruction) {
+        match instruction {
+            Instruction::Noop => self.pc += 1,
+            Instruction::Add(register, operand) => {
+                self.registers[register as usize] += operand;
+                self.pc += 1;
+            }
+            Instruction::Jmp(offset) => self.jump(offset),
+            Instruction::Jnz(register, offset) => {
+                if self.registers[register as usize] != 0 {
+                    self.jump(offset);
+                } else {
+                    self.pc += 1;
+                }
+            }
+        }
+    }
+
+    // Jumping outside of the program halts it
+    fn jump(&mut self, offset: i32) {
+        self.pc = self
+            .pc
+            .checked_add_signed(offset as isize)
+            .unwrap_or(self.program.len());
+    }
+
+    // One line per traced cycle with the instruction being executed and the register values
+    pub fn dump_trace(&self) -> String {
+        let Some(trace) = &self.trace else {
+            return String::new();
+        };
+
+        trace
+            .iter()
+            .map(|state| {
+                let registers: Vec<_> = Register::ALL
+                    .iter()
+                    .map(|&register| format!("{}={}", register.name(), state.register(register)))
+                    .collect();
+                format!(
+                    "cycle {:>4}  pc {:>4}  {:<10}  {}\n",
+                    state.cycle,
+                    state.pc,
+                    self.program[state.pc].to_string(),
+                    registers.join(" ")
+                )
+            })
+            .collect()
+    }
+}
+
+impl Iterator for Cpu<'_> {
+    type Item = CycleState;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.step()
+    }
+}
+
+const INITIAL_REGISTER_VALUE: i32 = 1;
+
+const START_COUNTING_CYCLE: usize = 20;
+const COUNTING_CYCLE_STEP: usize = 40;
+const LAST_COUNTING_CYCLE: usize = 220;
+
+const HORIZONTAL_RESOLUTION: usize = 40;
+const VERTICAL_RESOLUTION: usize = 6;
+
+fn solve(instructions: &[Instruction]) -> i32 {
+    let mut cpu = Cpu::new(instructions);
+    cpu.add_breakpoint(START_COUNTING_CYCLE);
+
+    // Stop after the last cycle that counts, as jumps allow programs that never halt
+    let mut sum = 0;
+    while let Some(state) = cpu.run_until_breakpoint() {
+        sum += state.cycle as i32 * state.register(Register::X);
+        if state.cycle >= LAST_COUNTING_CYCLE {
+            break;
+        }
+        cpu.add_breakpoint(state.cycle + COUNTING_CYCLE_STEP);
+    }
+
+    sum
+}
+
+// Returns the letters drawn on the screen, or the raw screen if it doesn't contain letters
+fn solve_part_2(instructions: &[Instruction]) -> String {
+    let screen = render_screen(instructions);
+    ocr::recognize(&screen).unwrap_or(screen)
+}
+
+fn render_screen(instructions: &[Instruction]) -> String {
+    let mut result = String::with_capacity(VERTICAL_RESOLUTION * (HORIZONTAL_RESOLUTION + 1));
+
+    let pixels = VERTICAL_RESOLUTION * HORIZONTAL_RESOLUTION;
+    for (i, state) in Cpu::new(instructions).take(pixels).enumerate() {
+        let x = state.register(Register::X);
+        let j = i % HORIZONTAL_RESOLUTION;
+        if j == 0 && i > 0 {
+            result.push('\n');
+        }
+
+        if ((j as i32) - x).abs() <= 1 {
+            result.push('#');
+        } else {
+            // Print space instead of . because it makes the output more readable
+            result.push(' ');
+        }
+    }
+
+    result
+}
+
+fn parse_input(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    error::numbered_lines(input)
+        .map(|(line_number, line)| Instruction::from_line(line_number, line))
+        .collect()
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u32 = 10;
+
+    type Input = Vec<Instruction>;
+    type Output1 = i32;
+    type Output2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Output1 {
+        solve(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Output2 {
+        solve_part_2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE_INPUT: &str = include_str!("sample_input/sample10.txt");
+
+    #[test]
+    fn test_sample_input_part_1() {
+        assert_eq!(13140, solve(&parse_input(SAMPLE_INPUT).unwrap()));
+    }
+
+    #[test]
+    fn test_sample_input_part_2() {
+        let expected = "\
+##..##..##..##..##..##..##..##..##..##..
+###...###...###...###...###...###...###.
+####....####....####....####....####....
+#####.....#####.....#####.....#####.....
+######......######......######......####
+#######.......#######.......#######.....";
+
+        // The sample doesn't draw letters, so the raw screen is returned
+        let expected = expected.replace('.', " ");
+        assert_eq!(expected, solve_part_2(&parse_input(SAMPLE_INPUT).unwrap()));
+    }
+
+    #[test]
+    fn test_extended_program() {
+        // Counts y down from 3 to 0, adding 2 to x each time around the loop
+        let program = parse_input("addy 2\naddx 2\naddy -1\njnz y -2\nnoop").unwrap();
+
+        let mut cpu = Cpu::new(&program);
+        cpu.enable_trace();
+        cpu.add_breakpoint(5);
+
+        let state = cpu.run_until_breakpoint().unwrap();
+        assert_eq!(
+            (3, 3),
+            (state.register(Register::X), state.register(Register::Y))
+        );
+        assert_eq!(2, state.pc);
+
+        assert_eq!(None, cpu.run_until_breakpoint());
+        assert!(cpu.is_halted());
+        assert_eq!(
+            (7, 0),
+            (cpu.register(Register::X), cpu.register(Register::Y))
+        );
+
+        let trace = cpu.dump_trace();
+        assert_eq!(21, trace.lines().count());
+        assert_eq!(
+            "cycle    7  pc    3  jnz y -2    x=3 y=2",
+            trace.lines().nth(6).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_endless_program() {
+        // X goes up by one every three cycles forever, and only cycles 20 to 220 are counted
+        let instructions = parse_input("addx 1\njmp -1").unwrap();
+        let expected: i32 = (20..=220)
+            .step_by(40)
+            .map(|cycle| cycle * (1 + cycle / 3))
+            .sum();
+        assert_eq!(expected, solve(&instructions));
+
+        // Jumping to itself leaves the sprite at the start of every row
+        let instructions = parse_input("jmp 0").unwrap();
+        let row = format!("###{}", " ".repeat(37));
+        assert_eq!([row.as_str(); 6].join("\n"), render_screen(&instructions));
+    }
+
+    #[test]
+    fn test_invalid_instruction() {
+        assert_eq!(
+            Err(ParseError::new(2, 4, "register should be x or y, was 'z'")),
+            parse_input("noop\naddz 3").map(|_| ())
+        );
+    }
+}
@@ -0,0 +1,310 @@
+//! Day 11: Monkey in the Middle
+//!
+//! <https://adventofcode.com/2022/day/11>
+
+use crate::error::{self, ParseError};
+use crate::solution::Solution;
+
+#[derive(Clone)]
+enum MonkeyOperation {
+    Add(u64),
+    Multiply(u64),
+    Square,
+}
+
+impl MonkeyOperation {
+    fn from_str(line_number: usize, line: &str, s: &str) -> Result<Self, ParseError> {
+        let split: Vec<_> = s.split(' ').collect();
+        match split.as_slice() {
+            ["old", "*", "old"] => Ok(Self::Square),
+            ["old", "*", operand] => {
+                let operand: u64 = error::parse_number(line_number, line, operand, "operand")?;
+                Ok(Self::Multiply(operand))
+            }
+            ["old", "+", operand] => {
+                let operand: u64 = error::parse_number(line_number, line, operand, "operand")?;
+                Ok(Self::Add(operand))
+            }
+            _ => Err(ParseError::at(
+                line_number,
+                line,
+                s,
+                format!("unexpected operation: {s}"),
+            )),
+        }
+    }
+
+    fn apply(&self, item: u64) -> u64 {
+        match *self {
+            Self::Add(operand) => item + operand,
+            Self::Multiply(operand) => item * operand,
+            Self::Square => item * item,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Monkey {
+    items: Vec<u64>,
+    operation: MonkeyOperation,
+    divisible_test: u64,
+    true_monkey: usize,
+    false_monkey: usize,
+}
+
+impl Monkey {
+    fn new(
+        items: Vec<u64>,
+        operation: MonkeyOperation,
+        divisible_test: u64,
+        true_monkey: usize,
+        false_monkey: usize,
+    ) -> Self {
+        Self {
+            items,
+            operation,
+            divisible_test,
+            true_monkey,
+            false_monkey,
+        }
+    }
+}
+
+const PART_1_ROUNDS: usize = 20;
+const PART_2_ROUNDS: usize = 10000;
+
+fn solve(monkeys: &[Monkey], rounds: usize, divide_by_three: bool) -> u64 {
+    let mut monkeys = monkeys.to_vec();
+
+    let test_product: u64 = monkeys.iter().map(|monkey| monkey.divisible_test).product();
+
+    let mut inspection_counts: Vec<u64> = vec![0; monkeys.len()];
+    for _ in 0..rounds {
+        for i in 0..monkeys.len() {
+            let mut monkey_items: Vec<u64> = Vec::with_capacity(monkeys[i].items.len());
+            monkey_items.append(&mut monkeys[i].items);
+
+            for &item in &monkey_items {
+                let mut item = monkeys[i].operation.apply(item);
+                if divide_by_three {
+                    item /= 3;
+                } else {
+                    // Why this works:
+                    // For any non-negative integers a and b and any positive integer n:
+                    //   (a + b) mod n == ((a mod n) + b) mod n
+                    //   (a * b) mod n == ((a mod n) * b) mod n
+                    // For any non-negative integer a and any positive integers n and k:
+                    //   (a mod kn) mod n == a mod n
+                    // All monkey operations are either addition or multiplication, and the product
+                    // of all of the divisors is by definition a multiple of every divisor.
+                    item %= test_product;
+                }
+
+                let new_monkey = if item.is_multiple_of(monkeys[i].divisible_test) {
+                    monkeys[i].true_monkey
+                } else {
+                    monkeys[i].false_monkey
+                };
+
+                monkeys[new_monkey].items.push(item);
+            }
+
+            inspection_counts[i] += monkey_items.len() as u64;
+        }
+    }
+
+    inspection_counts.sort_by(|a, b| b.cmp(a));
+    inspection_counts[0] * inspection_counts[1]
+}
+
+fn solve_part_1(monkeys: &[Monkey]) -> u64 {
+    solve(monkeys, PART_1_ROUNDS, true)
+}
+
+fn solve_part_2(monkeys: &[Monkey]) -> u64 {
+    solve(monkeys, PART_2_ROUNDS, false)
+}
+
+fn parse_input(input: &str) -> Result<Vec<Monkey>, ParseError> {
+    let blocks = error::numbered_blocks(input)?;
+    // Monkey business multiplies the two highest inspection counts
+    if blocks.len() < 2 {
+        return Err(ParseError::new(
+            blocks[0][0].0,
+            1,
+            "there should be at least two monkeys",
+        ));
+    }
+
+    blocks
+        .iter()
+        .enumerate()
+        .map(|(index, lines)| parse_monkey(index, blocks.len(), lines))
+        .collect()
+}
+
+fn parse_monkey(
+    index: usize,
+    monkey_count: usize,
+    lines: &[(usize, &str)],
+) -> Result<Monkey, ParseError> {
+    let mut iter = lines.iter().copied();
+
+    // Blocks from numbered_blocks are never empty
+    let (header_line_number, header) = iter.next().expect("should be monkey header line");
+    let expected_header = format!("Monkey {index}:");
+    if header != expected_header {
+        return Err(ParseError::new(
+            header_line_number,
+            1,
+            format!("header should be '{expected_header}', was '{header}'"),
+        ));
+    }
+
+    let mut next_line = |prefix: &str| {
+        let (line_number, line) = iter.next().ok_or_else(|| {
+            ParseError::new(
+                header_line_number + lines.len(),
+                1,
+                format!("missing line starting with '{prefix}'"),
+            )
+        })?;
+        match line.strip_prefix(prefix) {
+            Some(rest) => Ok((line_number, line, rest)),
+            None => Err(ParseError::new(
+                line_number,
+                1,
+                format!("line should start with '{prefix}'"),
+            )),
+        }
+    };
+    let check_target = |line_number: usize, line: &str, target_str: &str, target: usize| {
+        if target < monkey_count {
+            Ok(target)
+        } else {
+            Err(ParseError::at(
+                line_number,
+                line,
+                target_str,
+                format!("there is no monkey {target}; there are only {monkey_count} monkeys"),
+            ))
+        }
+    };
+
+    let (line_number, line, items) = next_line("  Starting items: ")?;
+    let starting_items: Vec<u64> = items
+        .split(", ")
+        .map(|item| error::parse_number(line_number, line, item, "starting item"))
+        .collect::<Result<_, _>>()?;
+
+    let (line_number, line, operation_str) = next_line("  Operation: new = ")?;
+    let operation = MonkeyOperation::from_str(line_number, line, operation_str)?;
+
+    let (line_number, line, divisor) = next_line("  Test: divisible by ")?;
+    let divisible_test: u64 = error::parse_number(line_number, line, divisor, "divisor")?;
+    if divisible_test == 0 {
+        return Err(ParseError::at(
+            line_number,
+            line,
+            divisor,
+            "divisor should not be 0",
+        ));
+    }
+
+    let (line_number, line, true_monkey) = next_line("    If true: throw to monkey ")?;
+    let true_monkey = check_target(
+        line_number,
+        line,
+        true_monkey,
+        error::parse_number(line_number, line, true_monkey, "if true monkey")?,
+    )?;
+
+    let (line_number, line, false_monkey) = next_line("    If false: throw to monkey ")?;
+    let false_monkey = check_target(
+        line_number,
+        line,
+        false_monkey,
+        error::parse_number(line_number, line, false_monkey, "if false monkey")?,
+    )?;
+
+    Ok(Monkey::new(
+        starting_items,
+        operation,
+        divisible_test,
+        true_monkey,
+        false_monkey,
+    ))
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u32 = 11;
+
+    type Input = Vec<Monkey>;
+    type Output1 = u64;
+    type Output2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Output1 {
+        solve_part_1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Output2 {
+        solve_part_2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE_INPUT: &str = include_str!("sample_input/sample11.txt");
+
+    #[test]
+    fn test_sample_input_part_1() {
+        assert_eq!(10605, solve_part_1(&parse_input(SAMPLE_INPUT).unwrap()));
+    }
+
+    #[test]
+    fn test_sample_input_part_2() {
+        assert_eq!(
+            2713310158,
+            solve_part_2(&parse_input(SAMPLE_INPUT).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let input = SAMPLE_INPUT.replacen("throw to monkey 2", "throw to monkey 7", 1);
+        assert_eq!(
+            Err(ParseError::new(
+                5,
+                30,
+                "there is no monkey 7; there are only 4 monkeys"
+            )),
+            parse_input(&input).map(|_| ())
+        );
+
+        let input = SAMPLE_INPUT.replacen("Monkey 1:", "Monkey 2:", 1);
+        assert_eq!(
+            Err(ParseError::new(
+                8,
+                1,
+                "header should be 'Monkey 1:', was 'Monkey 2:'"
+            )),
+            parse_input(&input).map(|_| ())
+        );
+
+        let input = SAMPLE_INPUT.replacen("\n\n", "\n\n\n", 1);
+        assert_eq!(
+            Err(ParseError::new(8, 1, "unexpected blank line")),
+            parse_input(&input).map(|_| ())
+        );
+        assert!(parse_input("").is_err());
+        assert!(parse_input(&SAMPLE_INPUT[..SAMPLE_INPUT.find("\n\n").unwrap()]).is_err());
+    }
+}
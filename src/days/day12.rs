@@ -0,0 +1,315 @@
+//! Day 12: Hill Climbing Algorithm
+//!
+//! <https://adventofcode.com/2022/day/12>
+
+use std::cmp::Ordering;
+use std::fmt::{self, Display};
+
+use crate::error::ParseError;
+use crate::grid::{Grid, Position};
+use crate::search::{self, SearchTree};
+use crate::solution::{Options, Solution};
+
+pub struct Input {
+    grid: Grid<u8>,
+    start: Position,
+    end: Position,
+    render: bool,
+}
+
+// The length of the shortest route, followed by the heightmap with the route drawn on it when
+// rendering is turned on
+#[derive(Debug, PartialEq, Eq)]
+pub struct Climb {
+    distance: usize,
+    rendered: Option<String>,
+}
+
+impl Display for Climb {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.distance)?;
+        if let Some(rendered) = &self.rendered {
+            write!(f, "\n{rendered}")?;
+        }
+        Ok(())
+    }
+}
+
+// Shortest distance from every square to the end, found with a single BFS backwards from the end
+// where each step may climb down at most one level instead of up
+pub struct DistanceField {
+    distances: Grid<Option<usize>>,
+    tree: SearchTree<Position, usize>,
+}
+
+impl DistanceField {
+    pub fn new(grid: &Grid<u8>, end: Position) -> Self {
+        let tree = search::bfs([end], |&position| {
+            grid.neighbours4(position)
+                .filter(move |&neighbour| grid[position] <= grid[neighbour] + 1)
+        });
+
+        let mut distances = grid.map(|_| None);
+        for (&position, &distance) in tree.distances() {
+            distances[position] = Some(distance);
+        }
+
+        Self { distances, tree }
+    }
+
+    // None if the end can't be reached from position
+    pub fn distance(&self, position: Position) -> Option<usize> {
+        self.distances[position]
+    }
+
+    pub fn distances(&self) -> &Grid<Option<usize>> {
+        &self.distances
+    }
+
+    // The route from position to the end, including both
+    pub fn route_from(&self, position: Position) -> Option<Vec<Position>> {
+        let mut route = self.tree.path_to(&position)?.nodes;
+        route.reverse();
+        Some(route)
+    }
+
+    // The square closest to the end among those whose height (0 for a through 25 for z) matches
+    pub fn best_start(
+        &self,
+        grid: &Grid<u8>,
+        mut is_start_height: impl FnMut(u8) -> bool,
+    ) -> Option<(Position, usize)> {
+        grid.iter()
+            .filter(|&(_, &height)| is_start_height(height))
+            .filter_map(|(position, _)| Some((position, self.distance(position)?)))
+            .min_by_key(|&(_, distance)| distance)
+    }
+}
+
+// Draw the route over the heightmap the way the puzzle text does: each step is an arrow pointing
+// at the next square, the end is E, and squares off the route are .
+pub fn render_route(grid: &Grid<u8>, route: &[Position]) -> String {
+    let mut rendered = grid.map(|_| '.');
+
+    for step in route.windows(2) {
+        let ((row, col), (next_row, next_col)) = (step[0], step[1]);
+        rendered[(row, col)] = match (next_row.cmp(&row), next_col.cmp(&col)) {
+            (Ordering::Less, _) => '^',
+            (Ordering::Greater, _) => 'v',
+            (_, Ordering::Less) => '<',
+            _ => '>',
+        };
+    }
+
+    if let Some(&end) = route.last() {
+        rendered[end] = 'E';
+    }
+
+    rendered.to_string()
+}
+
+fn solve_part_1(input: &Input) -> Climb {
+    let field = DistanceField::new(&input.grid, input.end);
+    let distance = field
+        .distance(input.start)
+        .expect("reaching E is checked while parsing");
+
+    climb(input, &field, input.start, distance)
+}
+
+fn solve_part_2(input: &Input) -> Climb {
+    let field = DistanceField::new(&input.grid, input.end);
+    let (start, distance) = field
+        .best_start(&input.grid, |height| height == 0)
+        .expect("reaching E is checked while parsing");
+
+    climb(input, &field, start, distance)
+}
+
+fn climb(input: &Input, field: &DistanceField, start: Position, distance: usize) -> Climb {
+    let rendered = input.render.then(|| {
+        let route = field
+            .route_from(start)
+            .expect("start is reachable when it has a distance");
+        render_route(&input.grid, &route)
+    });
+
+    Climb { distance, rendered }
+}
+
+fn parse_input(input: &str) -> Result<Input, ParseError> {
+    let chars = Grid::parse(input, |c| matches!(c, 'S' | 'E' | 'a'..='z').then_some(c))?;
+
+    let find_one = |marker: char, what: &str| {
+        let mut positions = chars
+            .iter()
+            .filter(|&(_, &c)| c == marker)
+            .map(|(position, _)| position);
+        let position = positions.next().ok_or_else(|| {
+            ParseError::new(1, 1, format!("heightmap should contain {what} {marker}"))
+        })?;
+        match positions.next() {
+            Some((row, col)) => Err(ParseError::new(
+                row + 1,
+                col + 1,
+                format!("heightmap should contain only one {marker}"),
+            )),
+            None => Ok(position),
+        }
+    };
+    let start = find_one('S', "a start")?;
+    let end = find_one('E', "an end")?;
+
+    let grid = chars.map(|&c| match c {
+        'S' => 0,
+        'E' => b'z' - b'a',
+        _ => (c as u8) - b'a',
+    });
+
+    // S is at the lowest height, so part 2 can always start from there if part 1 can
+    if DistanceField::new(&grid, end).distance(start).is_none() {
+        return Err(ParseError::new(
+            start.0 + 1,
+            start.1 + 1,
+            "E should be reachable from S",
+        ));
+    }
+
+    Ok(Input {
+        grid,
+        start,
+        end,
+        render: false,
+    })
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u32 = 12;
+
+    type Input = Input;
+    type Output1 = Climb;
+    type Output2 = Climb;
+
+    const OPTIONS: &'static [&'static str] = &["render"];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn configure(input: &mut Self::Input, options: &Options) -> Result<(), String> {
+        if let Some(render) = options.get("render") {
+            input.render = render
+                .parse()
+                .map_err(|_| format!("render should be true or false, was {render}"))?;
+        }
+        Ok(())
+    }
+
+    fn part1(input: &Self::Input) -> Self::Output1 {
+        solve_part_1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Output2 {
+        solve_part_2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::{Part, Solver};
+
+    const SAMPLE_INPUT: &str = include_str!("sample_input/sample12.txt");
+
+    #[test]
+    fn test_sample_input_part_1() {
+        assert_eq!(
+            31,
+            solve_part_1(&parse_input(SAMPLE_INPUT).unwrap()).distance
+        );
+    }
+
+    #[test]
+    fn test_sample_input_part_2() {
+        assert_eq!(
+            29,
+            solve_part_2(&parse_input(SAMPLE_INPUT).unwrap()).distance
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            Err(ParseError::new(2, 3, "heightmap should contain only one S")),
+            parse_input("Sab\nabS\nbcE").map(|_| ())
+        );
+        assert_eq!(
+            Err(ParseError::new(1, 1, "heightmap should contain an end E")),
+            parse_input("Sab").map(|_| ())
+        );
+        assert_eq!(
+            Err(ParseError::new(1, 1, "E should be reachable from S")),
+            parse_input("SacE").map(|_| ())
+        );
+    }
+
+    #[test]
+    fn test_render_route() {
+        let Input {
+            grid, start, end, ..
+        } = parse_input(SAMPLE_INPUT).unwrap();
+        let field = DistanceField::new(&grid, end);
+
+        let route = field.route_from(start).unwrap();
+        assert_eq!((start, end), (route[0], route[route.len() - 1]));
+        assert_eq!(32, route.len());
+
+        // Equally short as the route drawn in the puzzle text, but it heads right first
+        let expected = ">>vv<<<<\n..vvv<<^\n..vv>E^^\n..v>>>^^\n..>>>>>^";
+        assert_eq!(expected, render_route(&grid, &route));
+    }
+
+    #[test]
+    fn test_distance_field() {
+        let Input {
+            grid, start, end, ..
+        } = parse_input(SAMPLE_INPUT).unwrap();
+        let field = DistanceField::new(&grid, end);
+
+        assert_eq!(Some(0), field.distance(end));
+        assert_eq!(Some(31), field.distance(start));
+        assert_eq!(
+            40,
+            field
+                .distances()
+                .iter()
+                .filter(|(_, d)| d.is_some())
+                .count()
+        );
+
+        assert_eq!(
+            Some(((4, 0), 29)),
+            field.best_start(&grid, |height| height == 0)
+        );
+        assert_eq!(
+            Some(((4, 2), 25)),
+            field.best_start(&grid, |height| height == 2 || height == 3)
+        );
+        assert_eq!(None, field.best_start(&grid, |height| height > 25));
+    }
+
+    #[test]
+    fn test_render_option() {
+        let mut options = Options::new();
+        options.insert("render", "true");
+        let result = Day12.solve(SAMPLE_INPUT, &[Part::Two], &options).unwrap();
+
+        let expected = "29\n...v<<<<\n...vv<<^\n...v>E^^\n.>v>>>^^\n>^>>>>>^";
+        assert_eq!(expected, result.parts[0].answer);
+
+        options.insert("render", "yes");
+        assert!(Day12.solve(SAMPLE_INPUT, &[Part::One], &options).is_err());
+    }
+}
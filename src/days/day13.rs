@@ -0,0 +1,634 @@
+//! Day 13: Distress Signal
+//!
+//! <https://adventofcode.com/2022/day/13>
+
+use std::cmp::Ordering;
+use std::fmt::{self, Display};
+use std::iter::Peekable;
+use std::str::{CharIndices, FromStr};
+
+use crate::error::{self, ParseError};
+use crate::solution::{Options, Solution};
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum ListItem {
+    Int(u32),
+    List(Vec<ListItem>),
+}
+
+// The canonical packet form with no whitespace, e.g. [1,[2,3]]
+impl Display for ListItem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Int(n) => write!(f, "{n}"),
+            Self::List(items) => {
+                write!(f, "[")?;
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{item}")?;
+                }
+                write!(f, "]")
+            }
+        }
+    }
+}
+
+// Parses a single packet; errors are reported on line 1 at the offending column
+impl FromStr for ListItem {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_packet(1, s)
+    }
+}
+
+impl PartialOrd<Self> for ListItem {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ListItem {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Self::Int(a), Self::Int(b)) => a.cmp(b),
+            (Self::List(a), Self::List(b)) => {
+                for (a_item, b_item) in a.iter().zip(b) {
+                    match a_item.cmp(b_item) {
+                        Ordering::Equal => {}
+                        ordering => {
+                            return ordering;
+                        }
+                    }
+                }
+
+                a.len().cmp(&b.len())
+            }
+            (Self::Int(_), Self::List(_)) => {
+                let self_as_list = Self::List(vec![self.clone()]);
+                self_as_list.cmp(other)
+            }
+            (Self::List(_), Self::Int(_)) => other.cmp(self).reverse(),
+        }
+    }
+}
+
+// How a comparison was decided
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Decision {
+    LeftSmaller,
+    RightSmaller,
+    LeftRanOut,
+    RightRanOut,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum TraceAction {
+    Compare(ListItem, ListItem),
+    // The integer on one side is wrapped in a list to compare against the other side
+    ConvertLeft(ListItem),
+    ConvertRight(ListItem),
+    Decide(Decision),
+}
+
+// One line of the explanation, indented by depth like the puzzle text
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct TraceStep {
+    pub depth: usize,
+    pub action: TraceAction,
+}
+
+impl Display for TraceStep {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}- ", "  ".repeat(self.depth))?;
+        match &self.action {
+            TraceAction::Compare(left, right) => write!(f, "Compare {left} vs {right}"),
+            TraceAction::ConvertLeft(converted) => write!(
+                f,
+                "Mixed types; convert left to {converted} and retry comparison"
+            ),
+            TraceAction::ConvertRight(converted) => write!(
+                f,
+                "Mixed types; convert right to {converted} and retry comparison"
+            ),
+            TraceAction::Decide(decision) => {
+                let reason = match decision {
+                    Decision::LeftSmaller => "Left side is smaller",
+                    Decision::RightSmaller => "Right side is smaller",
+                    Decision::LeftRanOut => "Left side ran out of items",
+                    Decision::RightRanOut => "Right side ran out of items",
+                };
+                let order = match decision {
+                    Decision::LeftSmaller | Decision::LeftRanOut => "in the right order",
+                    Decision::RightSmaller | Decision::RightRanOut => "not in the right order",
+                };
+                write!(f, "{reason}, so inputs are {order}")
+            }
+        }
+    }
+}
+
+// Compare two packets the same way as Ord, also recording each step of the comparison
+pub fn trace_comparison(left: &ListItem, right: &ListItem) -> (Ordering, Vec<TraceStep>) {
+    let mut steps = Vec::new();
+    let ordering = trace_comparison_inner(left, right, 0, &mut steps);
+    (ordering, steps)
+}
+
+fn trace_comparison_inner(
+    left: &ListItem,
+    right: &ListItem,
+    depth: usize,
+    steps: &mut Vec<TraceStep>,
+) -> Ordering {
+    steps.push(TraceStep {
+        depth,
+        action: TraceAction::Compare(left.clone(), right.clone()),
+    });
+
+    match (left, right) {
+        (ListItem::Int(a), ListItem::Int(b)) => {
+            let ordering = a.cmp(b);
+            push_decision(
+                steps,
+                depth + 1,
+                ordering,
+                Decision::LeftSmaller,
+                Decision::RightSmaller,
+            );
+            ordering
+        }
+        (ListItem::List(a), ListItem::List(b)) => {
+            for (a_item, b_item) in a.iter().zip(b) {
+                match trace_comparison_inner(a_item, b_item, depth + 1, steps) {
+                    Ordering::Equal => {}
+                    ordering => {
+                        return ordering;
+                    }
+                }
+            }
+
+            let ordering = a.len().cmp(&b.len());
+            push_decision(
+                steps,
+                depth + 1,
+                ordering,
+                Decision::LeftRanOut,
+                Decision::RightRanOut,
+            );
+            ordering
+        }
+        (ListItem::Int(_), ListItem::List(_)) => {
+            let converted = ListItem::List(vec![left.clone()]);
+            steps.push(TraceStep {
+                depth: depth + 1,
+                action: TraceAction::ConvertLeft(converted.clone()),
+            });
+            trace_comparison_inner(&converted, right, depth + 1, steps)
+        }
+        (ListItem::List(_), ListItem::Int(_)) => {
+            let converted = ListItem::List(vec![right.clone()]);
+            steps.push(TraceStep {
+                depth: depth + 1,
+                action: TraceAction::ConvertRight(converted.clone()),
+            });
+            trace_comparison_inner(left, &converted, depth + 1, steps)
+        }
+    }
+}
+
+// Record why the comparison ended, unless the two sides were equal and it continues
+fn push_decision(
+    steps: &mut Vec<TraceStep>,
+    depth: usize,
+    ordering: Ordering,
+    if_less: Decision,
+    if_greater: Decision,
+) {
+    let decision = match ordering {
+        Ordering::Less => if_less,
+        Ordering::Greater => if_greater,
+        Ordering::Equal => return,
+    };
+    steps.push(TraceStep {
+        depth,
+        action: TraceAction::Decide(decision),
+    });
+}
+
+// The whole explanation, one step per line
+pub fn render_trace(steps: &[TraceStep]) -> String {
+    steps
+        .iter()
+        .map(TraceStep::to_string)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn solve(pairs: &[(ListItem, ListItem)]) -> usize {
+    pairs
+        .iter()
+        .enumerate()
+        .filter(|(_, (a, b))| a.cmp(b).is_le())
+        .map(|(i, _)| i + 1)
+        .sum()
+}
+
+// How part 2 finds where the divider packets end up
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum DividerMode {
+    // Sort every packet along with the dividers
+    #[default]
+    Sort,
+    // Count the packets that are less than each divider, without sorting
+    Count,
+}
+
+impl FromStr for DividerMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "sort" => Ok(Self::Sort),
+            "count" => Ok(Self::Count),
+            _ => Err(format!("divider mode should be sort or count, was {s}")),
+        }
+    }
+}
+
+pub struct Input {
+    pairs: Vec<(ListItem, ListItem)>,
+    dividers: Vec<ListItem>,
+    divider_mode: DividerMode,
+}
+
+// [[2]] and [[6]]
+fn default_dividers() -> Vec<ListItem> {
+    [2, 6]
+        .into_iter()
+        .map(|n| ListItem::List(vec![ListItem::List(vec![ListItem::Int(n)])]))
+        .collect()
+}
+
+// Dividers separated by ;, e.g. [[2]];[[6]]
+fn parse_dividers(s: &str) -> Result<Vec<ListItem>, String> {
+    s.split(';')
+        .map(|divider| {
+            divider
+                .parse()
+                .map_err(|err| format!("invalid divider packet {divider}: {err}"))
+        })
+        .collect()
+}
+
+// The 1-based index of each divider once the packets and dividers are sorted together, where
+// every packet and other divider that is strictly less comes first
+pub fn divider_indices_by_sorting<'a>(
+    packets: impl IntoIterator<Item = &'a ListItem>,
+    dividers: &[ListItem],
+) -> Vec<usize> {
+    let mut all_items: Vec<&ListItem> = packets.into_iter().collect();
+    all_items.extend(dividers);
+    all_items.sort();
+
+    dividers
+        .iter()
+        .map(|divider| all_items.partition_point(|&item| item < divider) + 1)
+        .collect()
+}
+
+// Same as divider_indices_by_sorting, but in a single pass over the packets
+pub fn divider_indices_by_counting<'a>(
+    packets: impl IntoIterator<Item = &'a ListItem>,
+    dividers: &[ListItem],
+) -> Vec<usize> {
+    let mut indices: Vec<_> = dividers
+        .iter()
+        .map(|divider| 1 + dividers.iter().filter(|&other| other < divider).count())
+        .collect();
+
+    for packet in packets {
+        for (index, divider) in indices.iter_mut().zip(dividers) {
+            if packet < divider {
+                *index += 1;
+            }
+        }
+    }
+
+    indices
+}
+
+fn solve_part_2(
+    pairs: &[(ListItem, ListItem)],
+    dividers: &[ListItem],
+    divider_mode: DividerMode,
+) -> usize {
+    let packets = pairs.iter().flat_map(|(a, b)| [a, b]);
+
+    let indices = match divider_mode {
+        DividerMode::Sort => divider_indices_by_sorting(packets, dividers),
+        DividerMode::Count => divider_indices_by_counting(packets, dividers),
+    };
+    indices.into_iter().product()
+}
+
+// Tracks the position within a single packet line so that errors can point at a column
+struct PacketReader<'a> {
+    line_number: usize,
+    line: &'a str,
+    chars: Peekable<CharIndices<'a>>,
+}
+
+impl<'a> PacketReader<'a> {
+    fn new(line_number: usize, line: &'a str) -> Self {
+        Self {
+            line_number,
+            line,
+            chars: line.char_indices().peekable(),
+        }
+    }
+
+    fn peek(&mut self) -> Option<char> {
+        self.chars.peek().map(|&(_, c)| c)
+    }
+
+    fn next(&mut self) -> Option<char> {
+        self.chars.next().map(|(_, c)| c)
+    }
+
+    fn error(&mut self, message: impl Into<String>) -> ParseError {
+        let index = self.chars.peek().map_or(self.line.len(), |&(i, _)| i);
+        ParseError::at(self.line_number, self.line, &self.line[index..], message)
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<(ListItem, ListItem)>, ParseError> {
+    error::numbered_blocks(input)?
+        .into_iter()
+        .map(|line_pair| {
+            let [a, b] = match line_pair.as_slice() {
+                [a, b] => [a, b],
+                _ => {
+                    let (line_number, _) = line_pair[0];
+                    return Err(ParseError::new(
+                        line_number,
+                        1,
+                        format!("packets should come in pairs, found {}", line_pair.len()),
+                    ));
+                }
+            };
+
+            let a = parse_packet(a.0, a.1)?;
+            let b = parse_packet(b.0, b.1)?;
+            Ok((a, b))
+        })
+        .collect()
+}
+
+fn parse_packet(line_number: usize, line: &str) -> Result<ListItem, ParseError> {
+    let mut reader = PacketReader::new(line_number, line);
+
+    let item = parse_list_item(&mut reader)?;
+    if reader.peek().is_some() {
+        return Err(reader.error("unexpected chars after end of packet"));
+    }
+
+    Ok(item)
+}
+
+fn parse_list_item(reader: &mut PacketReader) -> Result<ListItem, ParseError> {
+    match reader.peek() {
+        Some('[') => Ok(ListItem::List(parse_list(reader)?)),
+        Some('0'..='9') => parse_int(reader),
+        Some(c) => Err(reader.error(format!("unexpected char: {c}"))),
+        None => Err(reader.error("unexpected end of line")),
+    }
+}
+
+fn parse_int(reader: &mut PacketReader) -> Result<ListItem, ParseError> {
+    let start_error = reader.error("list item should be an integer");
+
+    let mut s = String::new();
+    while let Some(c) = reader.peek() {
+        if c == ',' || c == ']' {
+            break;
+        }
+
+        s.push(reader.next().unwrap());
+    }
+
+    s.parse().map(ListItem::Int).map_err(|_| start_error)
+}
+
+fn parse_list(reader: &mut PacketReader) -> Result<Vec<ListItem>, ParseError> {
+    // Skip '['
+    reader.next();
+
+    let mut items: Vec<ListItem> = Vec::new();
+    if reader.peek() == Some(']') {
+        reader.next();
+        return Ok(items);
+    }
+
+    loop {
+        items.push(parse_list_item(reader)?);
+
+        match reader.peek() {
+            Some(',') => {
+                reader.next();
+            }
+            Some(']') => {
+                reader.next();
+                break;
+            }
+            Some(c) => return Err(reader.error(format!("expected , or ] but found {c}"))),
+            None => return Err(reader.error("list should be closed with ]")),
+        }
+    }
+
+    Ok(items)
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u32 = 13;
+
+    type Input = Input;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    const OPTIONS: &'static [&'static str] = &["dividers", "divider-mode"];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(Input {
+            pairs: parse_input(input)?,
+            dividers: default_dividers(),
+            divider_mode: DividerMode::default(),
+        })
+    }
+
+    fn configure(input: &mut Self::Input, options: &Options) -> Result<(), String> {
+        if let Some(dividers) = options.get("dividers") {
+            input.dividers = parse_dividers(dividers)?;
+        }
+        if let Some(divider_mode) = options.get("divider-mode") {
+            input.divider_mode = divider_mode.parse()?;
+        }
+        Ok(())
+    }
+
+    fn part1(input: &Self::Input) -> Self::Output1 {
+        solve(&input.pairs)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Output2 {
+        solve_part_2(&input.pairs, &input.dividers, input.divider_mode)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE_INPUT: &str = include_str!("sample_input/sample13.txt");
+
+    #[test]
+    fn test_sample_input_part_1() {
+        assert_eq!(13, solve(&parse_input(SAMPLE_INPUT).unwrap()));
+    }
+
+    #[test]
+    fn test_sample_input_part_2() {
+        let pairs = parse_input(SAMPLE_INPUT).unwrap();
+        for divider_mode in [DividerMode::Sort, DividerMode::Count] {
+            assert_eq!(140, solve_part_2(&pairs, &default_dividers(), divider_mode));
+        }
+    }
+
+    #[test]
+    fn test_divider_modes_agree() {
+        let pairs = parse_input(SAMPLE_INPUT).unwrap();
+        let packets: Vec<_> = pairs.iter().flat_map(|(a, b)| [a, b]).collect();
+
+        // Includes a divider equal to one of the packets and a duplicated divider
+        let dividers = parse_dividers("[[2]];[];[1,1,3,1,1];[[6]];[[2]];[[[]]];[10]").unwrap();
+        let sorted = divider_indices_by_sorting(packets.iter().copied(), &dividers);
+        assert_eq!(
+            sorted,
+            divider_indices_by_counting(packets.iter().copied(), &dividers)
+        );
+        assert_eq!(vec![13, 1, 6, 18, 13, 4, 23], sorted);
+
+        assert!(parse_dividers("[[2]];[[6]").is_err());
+    }
+
+    #[test]
+    fn test_unclosed_list() {
+        assert_eq!(
+            Err(ParseError::new(2, 7, "list should be closed with ]")),
+            parse_input("[1]\n[[2],3").map(|_| ())
+        );
+    }
+
+    #[test]
+    fn test_empty_blocks() {
+        assert_eq!(
+            Err(ParseError::new(1, 1, "input should not be empty")),
+            parse_input("").map(|_| ())
+        );
+        assert_eq!(
+            Err(ParseError::new(4, 1, "unexpected blank line")),
+            parse_input("[1]\n[2]\n\n\n[3]\n[4]").map(|_| ())
+        );
+    }
+
+    #[test]
+    fn test_trace_comparison() {
+        let pairs = parse_input(SAMPLE_INPUT).unwrap();
+        for (a, b) in &pairs {
+            assert_eq!(a.cmp(b), trace_comparison(a, b).0);
+            assert_eq!(b.cmp(a), trace_comparison(b, a).0);
+        }
+
+        let (ordering, steps) = trace_comparison(&pairs[1].0, &pairs[1].1);
+        assert_eq!(Ordering::Less, ordering);
+        let expected = "\
+- Compare [[1],[2,3,4]] vs [[1],4]
+  - Compare [1] vs [1]
+    - Compare 1 vs 1
+  - Compare [2,3,4] vs 4
+    - Mixed types; convert right to [4] and retry comparison
+    - Compare [2,3,4] vs [4]
+      - Compare 2 vs 4
+        - Left side is smaller, so inputs are in the right order";
+        assert_eq!(expected, render_trace(&steps));
+
+        let (ordering, steps) = trace_comparison(&pairs[6].0, &pairs[6].1);
+        assert_eq!(Ordering::Greater, ordering);
+        assert_eq!(
+            Some(&TraceStep {
+                depth: 2,
+                action: TraceAction::Decide(Decision::RightRanOut),
+            }),
+            steps.last()
+        );
+        let expected = "\
+- Compare [[[]]] vs [[]]
+  - Compare [[]] vs []
+    - Right side ran out of items, so inputs are not in the right order";
+        assert_eq!(expected, render_trace(&steps));
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for (a, b) in parse_input(SAMPLE_INPUT).unwrap() {
+            for packet in [a, b] {
+                assert_eq!(Ok(packet.clone()), packet.to_string().parse());
+            }
+        }
+
+        let packets: Vec<_> = SAMPLE_INPUT
+            .lines()
+            .filter(|line| !line.is_empty())
+            .collect();
+        for packet in packets {
+            assert_eq!(packet, packet.parse::<ListItem>().unwrap().to_string());
+        }
+
+        assert_eq!(
+            "[1,[2,3]]",
+            ListItem::List(vec![
+                ListItem::Int(1),
+                ListItem::List(vec![ListItem::Int(2), ListItem::Int(3)])
+            ])
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn test_from_str_errors() {
+        assert_eq!(
+            Err(ParseError::new(1, 5, "expected , or ] but found 2")),
+            "[[1]2]".parse::<ListItem>()
+        );
+        assert_eq!(
+            Err(ParseError::new(1, 4, "unexpected char: ]")),
+            "[1,]".parse::<ListItem>()
+        );
+        assert_eq!(
+            Err(ParseError::new(
+                1,
+                4,
+                "unexpected chars after end of packet"
+            )),
+            "[1]]".parse::<ListItem>()
+        );
+        assert_eq!(
+            Err(ParseError::new(1, 2, "list item should be an integer")),
+            "[1x]".parse::<ListItem>()
+        );
+    }
+}
@@ -0,0 +1,497 @@
+//! Day 14: Regolith Reservoir
+//!
+//! <https://adventofcode.com/2022/day/14>
+
+use std::collections::{HashMap, HashSet};
+use std::str::FromStr;
+
+use crate::error::{self, ParseError};
+use crate::point::Point2;
+use crate::solution::{Options, Solution};
+
+type Point = Point2<i32>;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Tile {
+    Rock,
+    Sand,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct SparseGrid {
+    grid: HashMap<Point, Tile>,
+}
+
+impl SparseGrid {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get(&self, point: Point) -> Option<Tile> {
+        self.grid.get(&point).copied()
+    }
+
+    fn is_occupied(&self, point: Point) -> bool {
+        self.grid.contains_key(&point)
+    }
+
+    pub fn set(&mut self, point: Point, tile: Tile) {
+        self.grid.insert(point, tile);
+    }
+
+    // Fill in rock along each horizontal or vertical line from one point of path to the next
+    pub fn add_rock_path(&mut self, path: &[Point]) {
+        for window in path.windows(2) {
+            let (mut p, end) = (window[0], window[1]);
+
+            self.set(p, Tile::Rock);
+            while p != end {
+                p += (end - p).signum();
+                self.set(p, Tile::Rock);
+            }
+        }
+    }
+
+    fn max_row(&self) -> Option<i32> {
+        self.grid.keys().map(|point| point.y).max()
+    }
+
+    // Top left and bottom right corners of everything in the grid
+    fn bounds(&self) -> Option<(Point, Point)> {
+        let mut points = self.grid.keys();
+        let first = *points.next()?;
+        Some(points.fold((first, first), |(min, max), point| {
+            (
+                Point::new(min.x.min(point.x), min.y.min(point.y)),
+                Point::new(max.x.max(point.x), max.y.max(point.y)),
+            )
+        }))
+    }
+}
+
+const POUR_START: Point = Point::new(500, 0);
+
+// How part 2 works out how much sand piles up on the floor
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum FloorMethod {
+    // Drop every grain with SandSimulator
+    #[default]
+    Simulate,
+    // Compute the filled area directly with fill_to_floor
+    Fill,
+}
+
+impl FromStr for FloorMethod {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "simulate" => Ok(Self::Simulate),
+            "fill" => Ok(Self::Fill),
+            _ => Err(format!("floor method should be simulate or fill, was {s}")),
+        }
+    }
+}
+
+pub struct Input {
+    rocks: SparseGrid,
+    sources: Vec<Point>,
+    floor_method: FloorMethod,
+}
+
+impl Input {
+    pub fn rocks(&self) -> &SparseGrid {
+        &self.rocks
+    }
+}
+
+// Pours sand one grain at a time, taking turns between the sources. A source stops pouring once
+// its grains fall into the abyss or once sand has piled up to cover it.
+pub struct SandSimulator {
+    grid: SparseGrid,
+    active_sources: Vec<Point>,
+    all_sources: Vec<Point>,
+    next_source: usize,
+    // Lowest row that sand can come to rest in
+    max_row: i32,
+    has_floor: bool,
+    sand_count: usize,
+}
+
+impl SandSimulator {
+    // With has_floor, there is an infinite floor two rows below the lowest rock instead of an abyss
+    pub fn new(rocks: &SparseGrid, sources: &[Point], has_floor: bool) -> Self {
+        let max_row = rocks.max_row().unwrap_or(0);
+
+        Self {
+            grid: rocks.clone(),
+            active_sources: sources.to_vec(),
+            all_sources: sources.to_vec(),
+            next_source: 0,
+            max_row: if has_floor { max_row + 1 } else { max_row },
+            has_floor,
+            sand_count: 0,
+        }
+    }
+
+    pub fn sand_count(&self) -> usize {
+        self.sand_count
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.active_sources.is_empty()
+    }
+
+    // Drop a single grain, returning where it came to rest or None if every source has stopped
+    pub fn step(&mut self) -> Option<Point> {
+        while !self.active_sources.is_empty() {
+            let index = self.next_source % self.active_sources.len();
+            let source = self.active_sources[index];
+
+            let resting = if self.grid.is_occupied(source) {
+                None
+            } else {
+                self.fall(source)
+            };
+
+            match resting {
+                Some(point) => {
+                    self.grid.set(point, Tile::Sand);
+                    self.sand_count += 1;
+                    self.next_source = index + 1;
+                    return Some(point);
+                }
+                None => {
+                    self.active_sources.remove(index);
+                    self.next_source = index;
+                }
+            }
+        }
+
+        None
+    }
+
+    // Drop grains until every source has stopped, returning the total amount of sand at rest
+    pub fn run(&mut self) -> usize {
+        while self.step().is_some() {}
+        self.sand_count
+    }
+
+    // Where a grain dropped from source comes to rest, or None if it falls into the abyss
+    fn fall(&self, source: Point) -> Option<Point> {
+        let mut sand = source;
+
+        while sand.y < self.max_row {
+            let next = [0, -1, 1]
+                .into_iter()
+                .map(|dx| Point::new(sand.x + dx, sand.y + 1))
+                .find(|&point| !self.grid.is_occupied(point));
+            match next {
+                Some(next) => sand = next,
+                None => return Some(sand),
+            }
+        }
+
+        self.has_floor.then_some(sand)
+    }
+
+    // The cave cropped to everything in it, drawn like the puzzle text
+    pub fn render(&self) -> String {
+        let Some((mut min, mut max)) = self.grid.bounds() else {
+            return String::new();
+        };
+        for source in &self.all_sources {
+            min = Point::new(min.x.min(source.x), min.y.min(source.y));
+            max = Point::new(max.x.max(source.x), max.y.max(source.y));
+        }
+
+        let floor_row = self.has_floor.then_some(self.max_row + 1);
+        if let Some(floor_row) = floor_row {
+            max.y = floor_row;
+        }
+
+        let lines: Vec<String> = (min.y..=max.y)
+            .map(|y| {
+                (min.x..=max.x)
+                    .map(|x| {
+                        let point = Point::new(x, y);
+                        match self.grid.get(point) {
+                            Some(Tile::Rock) => '#',
+                            Some(Tile::Sand) => 'o',
+                            None if Some(y) == floor_row => '#',
+                            None if self.all_sources.contains(&point) => '+',
+                            None => '.',
+                        }
+                    })
+                    .collect()
+            })
+            .collect();
+        lines.join("\n")
+    }
+}
+
+// The amount of sand that piles up once there is a floor, without simulating each grain. With a
+// floor, sand ends up in every open square that can be reached from a source by moving down or
+// diagonally down, so the filled area is built row by row: a square is filled if it isn't rock
+// and one of the three squares above it is filled.
+pub fn fill_to_floor(rocks: &SparseGrid, sources: &[Point]) -> usize {
+    let Some(min_row) = sources.iter().map(|source| source.y).min() else {
+        return 0;
+    };
+    // The floor is two rows below the lowest rock, so sand can rest just above it
+    let max_row = rocks.max_row().unwrap_or(0) + 1;
+
+    let mut count = 0;
+    let mut filled: HashSet<i32> = HashSet::new();
+    for y in min_row..=max_row {
+        let below_filled = filled.iter().flat_map(|&x| [x - 1, x, x + 1]);
+        let sources_in_row = sources
+            .iter()
+            .filter(|source| source.y == y)
+            .map(|source| source.x);
+        filled = below_filled
+            .chain(sources_in_row)
+            .filter(|&x| !rocks.is_occupied(Point::new(x, y)))
+            .collect();
+
+        count += filled.len();
+    }
+
+    count
+}
+
+fn solve(input: &Input, has_floor: bool) -> usize {
+    if has_floor && input.floor_method == FloorMethod::Fill {
+        return fill_to_floor(&input.rocks, &input.sources);
+    }
+
+    SandSimulator::new(&input.rocks, &input.sources, has_floor).run()
+}
+
+// Sources separated by ;, e.g. 500,0;520,0
+fn parse_sources(s: &str) -> Result<Vec<Point>, String> {
+    s.split(';')
+        .map(|source| {
+            source
+                .split_once(',')
+                .and_then(|(x, y)| Some(Point::new(x.parse().ok()?, y.parse().ok()?)))
+                .ok_or_else(|| format!("sand source should be x,y, was {source}"))
+        })
+        .collect()
+}
+
+fn parse_input(input: &str) -> Result<Input, ParseError> {
+    let paths: Vec<_> = error::numbered_lines(input)
+        .map(|(line_number, line)| parse_line(line_number, line))
+        .collect::<Result<_, _>>()?;
+
+    let mut sparse_grid = SparseGrid::new();
+    for path in &paths {
+        sparse_grid.add_rock_path(path);
+    }
+
+    if sparse_grid.grid.is_empty() {
+        return Err(ParseError::new(
+            1,
+            1,
+            "input should contain at least one rock",
+        ));
+    }
+
+    Ok(Input {
+        rocks: sparse_grid,
+        sources: vec![POUR_START],
+        floor_method: FloorMethod::default(),
+    })
+}
+
+fn parse_line(line_number: usize, line: &str) -> Result<Vec<Point>, ParseError> {
+    line.split(" -> ")
+        .map(|point| {
+            let (x, y) = point.split_once(',').ok_or_else(|| {
+                ParseError::at(line_number, line, point, "point should have one comma")
+            })?;
+            let x: i32 = error::parse_number(line_number, line, x, "point x")?;
+            let y: i32 = error::parse_number(line_number, line, y, "point y")?;
+            Ok(Point::new(x, y))
+        })
+        .collect()
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u32 = 14;
+
+    type Input = Input;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    const OPTIONS: &'static [&'static str] = &["sources", "floor-method"];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn configure(input: &mut Self::Input, options: &Options) -> Result<(), String> {
+        if let Some(sources) = options.get("sources") {
+            input.sources = parse_sources(sources)?;
+        }
+        if let Some(floor_method) = options.get("floor-method") {
+            input.floor_method = floor_method.parse()?;
+        }
+        Ok(())
+    }
+
+    fn part1(input: &Self::Input) -> Self::Output1 {
+        solve(input, false)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Output2 {
+        solve(input, true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bench::Rng;
+
+    const SAMPLE_INPUT: &str = include_str!("sample_input/sample14.txt");
+
+    #[test]
+    fn test_sample_input_part_1() {
+        assert_eq!(24, solve(&parse_input(SAMPLE_INPUT).unwrap(), false));
+    }
+
+    #[test]
+    fn test_sample_input_part_2() {
+        let mut input = parse_input(SAMPLE_INPUT).unwrap();
+        assert_eq!(93, solve(&input, true));
+
+        input.floor_method = FloorMethod::Fill;
+        assert_eq!(93, solve(&input, true));
+    }
+
+    #[test]
+    fn test_fill_matches_simulation() {
+        let mut rng = Rng::new(0x1414_2022);
+        let mut next = |bound: i32| rng.next(bound as u64) as i32;
+
+        for _ in 0..50 {
+            let mut lines = Vec::new();
+            for _ in 0..1 + next(6) {
+                let (x, y) = (480 + next(40), 2 + next(15));
+                let (dx, dy) = if next(2) == 0 {
+                    (next(8), 0)
+                } else {
+                    (0, next(8))
+                };
+                lines.push(format!("{x},{y} -> {},{}", x + dx, y + dy));
+            }
+            let input = parse_input(&lines.join("\n")).unwrap();
+
+            let sources = [POUR_START, Point::new(490 + next(20), next(2))];
+            for sources in [&sources[..1], &sources[..]] {
+                assert_eq!(
+                    SandSimulator::new(&input.rocks, sources, true).run(),
+                    fill_to_floor(&input.rocks, sources),
+                    "cave:\n{}\nsources: {sources:?}",
+                    lines.join("\n")
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_render() {
+        let input = parse_input(SAMPLE_INPUT).unwrap();
+        let mut simulator = SandSimulator::new(&input.rocks, &input.sources, false);
+
+        let expected = "\
+......+...
+..........
+..........
+..........
+....#...##
+....#...#.
+..###...#.
+........#.
+........#.
+#########.";
+        assert_eq!(expected, simulator.render());
+
+        assert_eq!(Some(Point::new(500, 8)), simulator.step());
+        assert_eq!(Some(Point::new(499, 8)), simulator.step());
+        assert_eq!(2, simulator.sand_count());
+
+        assert_eq!(24, simulator.run());
+        assert!(simulator.is_finished());
+        let expected = "\
+......+...
+..........
+......o...
+.....ooo..
+....#ooo##
+...o#ooo#.
+..###ooo#.
+....oooo#.
+.o.ooooo#.
+#########.";
+        assert_eq!(expected, simulator.render());
+    }
+
+    #[test]
+    fn test_multiple_sources() {
+        // A single flat rock with sources above either end, which sand soon spills off
+        let input = parse_input("0,2 -> 6,2").unwrap();
+        let sources = parse_sources("1,0;5,0").unwrap();
+
+        let mut simulator = SandSimulator::new(&input.rocks, &sources, false);
+        assert_eq!(Some(Point::new(1, 1)), simulator.step());
+        assert_eq!(Some(Point::new(5, 1)), simulator.step());
+        assert_eq!(3, simulator.run());
+        let expected = "\
+.+...+.
+.o..oo.
+#######";
+        assert_eq!(expected, simulator.render());
+
+        let mut simulator = SandSimulator::new(&input.rocks, &sources, true);
+        simulator.run();
+        assert_eq!(Some(Tile::Sand), simulator.grid.get(Point::new(1, 0)));
+        assert_eq!(Some(Tile::Sand), simulator.grid.get(Point::new(5, 0)));
+
+        assert!(parse_sources("500").is_err());
+    }
+
+    #[test]
+    fn test_custom_layout() {
+        // The same shelf as test_multiple_sources, built without the puzzle's input format
+        let mut rocks = SparseGrid::new();
+        rocks.add_rock_path(&[Point::new(0, 2), Point::new(6, 2)]);
+        assert_eq!(
+            parse_input("0,2 -> 6,2").unwrap().rocks().bounds(),
+            rocks.bounds()
+        );
+
+        // A cup three tiles wide and deep with one extra rock inside. The top corners stay empty,
+        // as the last grain comes to rest on the source once both tiles below it are taken
+        let mut rocks = SparseGrid::new();
+        rocks.add_rock_path(&[
+            Point::new(0, 0),
+            Point::new(0, 3),
+            Point::new(4, 3),
+            Point::new(4, 0),
+        ]);
+        rocks.set(Point::new(1, 2), Tile::Rock);
+        let mut simulator = SandSimulator::new(&rocks, &[Point::new(2, 0)], false);
+        assert_eq!(6, simulator.run());
+        let expected = "\
+#.o.#
+#ooo#
+##oo#
+#####";
+        assert_eq!(expected, simulator.render());
+    }
+}
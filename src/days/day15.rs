@@ -0,0 +1,699 @@
+//! Day 15: Beacon Exclusion Zone
+//!
+//! <https://adventofcode.com/2022/day/15>
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+use crate::error::{self, ParseError};
+use crate::interval::{Interval, IntervalSet};
+use crate::point::Point2;
+use crate::solution::{Options, Solution};
+
+type Point = Point2<i32>;
+
+// How far a sensor can detect, measured from the sensor to a point
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum Metric {
+    // Sensors cover a diamond, as in the puzzle
+    #[default]
+    Manhattan,
+    // Sensors cover a square
+    Chebyshev,
+}
+
+impl Metric {
+    fn distance(self, a: Point, b: Point) -> i32 {
+        match self {
+            Self::Manhattan => a.manhattan_distance(b),
+            Self::Chebyshev => a.chebyshev_distance(b),
+        }
+    }
+}
+
+impl FromStr for Metric {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "manhattan" => Ok(Self::Manhattan),
+            "chebyshev" => Ok(Self::Chebyshev),
+            _ => Err(format!("metric should be manhattan or chebyshev, was {s}")),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Sensor {
+    coordinates: Point,
+    nearest_beacon: Point,
+    nearest_beacon_distance: i32,
+    metric: Metric,
+}
+
+impl Sensor {
+    pub fn new(coordinates: Point, nearest_beacon: Point, metric: Metric) -> Self {
+        let nearest_beacon_distance = metric.distance(coordinates, nearest_beacon);
+        Self {
+            coordinates,
+            nearest_beacon,
+            nearest_beacon_distance,
+            metric,
+        }
+    }
+
+    fn distance_to(&self, p: &Point) -> i32 {
+        self.metric.distance(self.coordinates, *p)
+    }
+
+    // The x coordinates in row y that are no further from the sensor than its nearest beacon
+    fn row_coverage(&self, y: i32) -> Option<Interval<i32>> {
+        let dy = (y - self.coordinates.y).abs();
+        let radius = match self.metric {
+            Metric::Manhattan => self.nearest_beacon_distance - dy,
+            Metric::Chebyshev if dy <= self.nearest_beacon_distance => self.nearest_beacon_distance,
+            Metric::Chebyshev => -1,
+        };
+        (radius >= 0)
+            .then(|| Interval::new(self.coordinates.x - radius, self.coordinates.x + radius))
+    }
+}
+
+// The x coordinates in row y that are within range of at least one sensor
+pub fn covered_intervals(sensors: &[Sensor], y: i32) -> IntervalSet<i32> {
+    sensors
+        .iter()
+        .filter_map(|sensor| sensor.row_coverage(y))
+        .collect()
+}
+
+// The rectangle from min to max, inclusive, that part 2 searches
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Region {
+    pub min: Point,
+    pub max: Point,
+}
+
+impl Region {
+    // From 0 to max_coordinate in both directions
+    pub fn square(max_coordinate: i32) -> Self {
+        Self {
+            min: Point::new(0, 0),
+            max: Point::new(max_coordinate, max_coordinate),
+        }
+    }
+
+    pub fn contains(&self, p: &Point) -> bool {
+        (self.min.x..=self.max.x).contains(&p.x) && (self.min.y..=self.max.y).contains(&p.y)
+    }
+}
+
+const PART_1_ROW_TO_CHECK: i32 = 2000000;
+
+const PART_2_MAX_COORDINATE: i32 = 4000000;
+
+fn solve(sensors: &[Sensor], y_to_check: i32) -> usize {
+    let covered = covered_intervals(sensors, y_to_check);
+
+    // Every beacon is covered by its own sensor, so any in this row must be taken back out
+    let beacons_in_row: HashSet<_> = sensors
+        .iter()
+        .map(|sensor| sensor.nearest_beacon)
+        .filter(|beacon| beacon.y == y_to_check)
+        .collect();
+
+    covered.len() as usize - beacons_in_row.len()
+}
+
+// How part 2 looks for the point that no sensor covers
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum GapSearch {
+    // Check every point just outside of each sensor's range
+    #[default]
+    Perimeter,
+    // Check only where the edges just outside of the sensors' ranges cross
+    Intersections,
+}
+
+impl FromStr for GapSearch {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "perimeter" => Ok(Self::Perimeter),
+            "intersections" => Ok(Self::Intersections),
+            _ => Err(format!(
+                "search should be perimeter or intersections, was {s}"
+            )),
+        }
+    }
+}
+
+pub struct Input {
+    sensors: Vec<Sensor>,
+    row_to_check: i32,
+    region: Region,
+    gap_search: GapSearch,
+}
+
+impl Input {
+    pub fn sensors(&self) -> &[Sensor] {
+        &self.sensors
+    }
+}
+
+fn tuning_frequency(point: Point) -> i64 {
+    (point.x as i64) * 4000000 + (point.y as i64)
+}
+
+// What part 2 found in the region, since a region given as an option may not have exactly one gap
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum DistressBeacon {
+    Found(Point),
+    NotFound,
+    NotUnique,
+}
+
+impl Display for DistressBeacon {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Found(point) => write!(f, "{}", tuning_frequency(*point)),
+            Self::NotFound => write!(f, "no uncovered point found"),
+            Self::NotUnique => write!(f, "more than one uncovered point found"),
+        }
+    }
+}
+
+fn solve_part_2(sensors: &[Sensor], region: Region, gap_search: GapSearch) -> DistressBeacon {
+    if gap_search == GapSearch::Intersections {
+        let gaps = uncovered_rows(sensors, region);
+        let count: i64 = gaps.values().map(|row| row.len() as i64).sum();
+        return match gaps.iter().next() {
+            None => DistressBeacon::NotFound,
+            Some((&y, row)) if count == 1 => {
+                DistressBeacon::Found(Point::new(row.intervals()[0].start, y))
+            }
+            Some(_) => DistressBeacon::NotUnique,
+        };
+    }
+
+    // Any gap touches the edge just outside of some sensor's range, unless nothing covers any of
+    // the region, in which case its corners are uncovered
+    let corners = [
+        region.min,
+        Point::new(region.max.x, region.min.y),
+        Point::new(region.min.x, region.max.y),
+        region.max,
+    ];
+    let uncovered: HashSet<Point> = sensors
+        .iter()
+        .flat_map(|sensor| generate_candidate_points(sensor, region))
+        .chain(corners)
+        .filter(|p| is_uncovered(sensors, p))
+        .collect();
+
+    match uncovered.len() {
+        0 => DistressBeacon::NotFound,
+        1 => DistressBeacon::Found(*uncovered.iter().next().unwrap()),
+        _ => DistressBeacon::NotUnique,
+    }
+}
+
+fn is_uncovered(sensors: &[Sensor], p: &Point) -> bool {
+    sensors
+        .iter()
+        .all(|sensor| sensor.distance_to(p) > sensor.nearest_beacon_distance)
+}
+
+// Every uncovered point in the region, ordered by row and then column
+pub fn uncovered_points(sensors: &[Sensor], region: Region) -> Vec<Point> {
+    uncovered_rows(sensors, region)
+        .into_iter()
+        .flat_map(|(y, row)| {
+            row.intervals()
+                .iter()
+                .flat_map(|interval| interval.start..=interval.end)
+                .map(move |x| Point::new(x, y))
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+// The uncovered x coordinates of each row in the region that has any. Every gap has a corner
+// where the edges just outside of the sensors' ranges cross, so each gap is filled in row by row
+// from its corners without checking the rows that have no gaps at all.
+pub fn uncovered_rows(sensors: &[Sensor], region: Region) -> BTreeMap<i32, IntervalSet<i32>> {
+    let mut row_gaps: HashMap<i32, IntervalSet<i32>> = HashMap::new();
+    let mut gaps_in_row = |y: i32| {
+        row_gaps
+            .entry(y)
+            .or_insert_with(|| covered_intervals(sensors, y).gaps(region.min.x, region.max.x))
+            .intervals()
+            .to_vec()
+    };
+
+    let mut queue: Vec<(i32, Interval<i32>)> = Vec::new();
+    for corner in gap_corners(sensors, region) {
+        let gap = gaps_in_row(corner.y)
+            .into_iter()
+            .find(|gap| gap.contains(corner.x))
+            .expect("corners should be uncovered");
+        queue.push((corner.y, gap));
+    }
+
+    let mut uncovered: BTreeMap<i32, IntervalSet<i32>> = BTreeMap::new();
+    while let Some((y, gap)) = queue.pop() {
+        let row = uncovered.entry(y).or_default();
+        if row.contains(gap.start) {
+            continue;
+        }
+        row.insert(gap.start, gap.end);
+
+        // Gaps in the rows above and below that share a column or a corner are part of the same
+        // region, as gaps between two diamonds run diagonally
+        for next_y in [y - 1, y + 1] {
+            if !(region.min.y..=region.max.y).contains(&next_y) {
+                continue;
+            }
+            queue.extend(
+                gaps_in_row(next_y)
+                    .into_iter()
+                    .filter(|next| next.start <= gap.end + 1 && gap.start - 1 <= next.end)
+                    .map(|next| (next_y, next)),
+            );
+        }
+    }
+    uncovered
+}
+
+// The uncovered points in the region where the edges just outside of the sensors' ranges cross
+// each other or the bounds. Every gap that is a single point is one of these, and a larger gap
+// has at least its corners among them.
+fn gap_corners(sensors: &[Sensor], region: Region) -> Vec<Point> {
+    let Region { min, max } = region;
+
+    // Diamonds have edges on lines of the form x + y = a and x - y = b, while squares have edges
+    // on lines of the form x = c and y = d
+    let mut sums: Vec<i32> = Vec::new();
+    let mut differences: Vec<i32> = Vec::new();
+    let mut columns: Vec<i32> = vec![min.x, max.x];
+    let mut rows: Vec<i32> = vec![min.y, max.y];
+    for sensor in sensors {
+        let Point { x, y } = sensor.coordinates;
+        let distance = sensor.nearest_beacon_distance + 1;
+        match sensor.metric {
+            Metric::Manhattan => {
+                sums.extend([x + y - distance, x + y + distance]);
+                differences.extend([x - y - distance, x - y + distance]);
+            }
+            Metric::Chebyshev => {
+                columns.extend([x - distance, x + distance]);
+                rows.extend([y - distance, y + distance]);
+            }
+        }
+    }
+
+    let mut candidates: Vec<Point> = Vec::new();
+    for &a in &sums {
+        for &b in &differences {
+            if (a + b) % 2 == 0 {
+                candidates.push(Point::new((a + b) / 2, (a - b) / 2));
+            }
+        }
+        candidates.extend(columns.iter().map(|&x| Point::new(x, a - x)));
+        candidates.extend(rows.iter().map(|&y| Point::new(a - y, y)));
+    }
+    for &b in &differences {
+        candidates.extend(columns.iter().map(|&x| Point::new(x, x - b)));
+        candidates.extend(rows.iter().map(|&y| Point::new(y + b, y)));
+    }
+    for &x in &columns {
+        candidates.extend(rows.iter().map(|&y| Point::new(x, y)));
+    }
+
+    let mut uncovered: Vec<_> = candidates
+        .into_iter()
+        .filter(|p| region.contains(p))
+        .filter(|p| is_uncovered(sensors, p))
+        .collect();
+    uncovered.sort_by_key(|p| (p.y, p.x));
+    uncovered.dedup();
+    uncovered
+}
+
+// Return all points that are (nearest_beacon_distance + 1) away from the sensor and within bounds
+fn generate_candidate_points(sensor: &Sensor, region: Region) -> impl Iterator<Item = Point> {
+    let distance = sensor.nearest_beacon_distance + 1;
+    let center = sensor.coordinates;
+
+    let sides = match sensor.metric {
+        Metric::Manhattan => [
+            (Point::new(-distance, 0), Point::new(1, 1)),
+            (Point::new(0, distance), Point::new(1, -1)),
+            (Point::new(distance, 0), Point::new(-1, -1)),
+            (Point::new(0, -distance), Point::new(-1, 1)),
+        ],
+        Metric::Chebyshev => [
+            (Point::new(-distance, -distance), Point::new(1, 0)),
+            (Point::new(distance, -distance), Point::new(0, 1)),
+            (Point::new(distance, distance), Point::new(-1, 0)),
+            (Point::new(-distance, distance), Point::new(0, -1)),
+        ],
+    };
+    let side_length = match sensor.metric {
+        Metric::Manhattan => distance,
+        Metric::Chebyshev => 2 * distance,
+    };
+
+    sides
+        .into_iter()
+        .flat_map(move |(start, step)| (0..side_length).map(move |d| center + start + step * d))
+        .filter(move |p| region.contains(p))
+}
+
+fn parse_input(input: &str) -> Result<Vec<Sensor>, ParseError> {
+    error::numbered_lines(input)
+        .map(|(line_number, line)| {
+            let (sensor, beacon) = line
+                .strip_prefix("Sensor at ")
+                .and_then(|rest| rest.split_once(": closest beacon is at "))
+                .ok_or_else(|| {
+                    ParseError::new(
+                        line_number,
+                        1,
+                        "line should be in the format 'Sensor at <point>: closest beacon is at <point>'",
+                    )
+                })?;
+
+            let coordinates = parse_coordinates(line_number, line, sensor)?;
+            let nearest_beacon = parse_coordinates(line_number, line, beacon)?;
+
+            Ok(Sensor::new(coordinates, nearest_beacon, Metric::default()))
+        })
+        .collect()
+}
+
+fn parse_coordinates(
+    line_number: usize,
+    line: &str,
+    coordinates: &str,
+) -> Result<Point, ParseError> {
+    let (x, y) = coordinates
+        .split_once(", ")
+        .and_then(|(x, y)| Some((x.strip_prefix("x=")?, y.strip_prefix("y=")?)))
+        .ok_or_else(|| {
+            ParseError::at(
+                line_number,
+                line,
+                coordinates,
+                "coordinates should be in the format 'x=<x>, y=<y>'",
+            )
+        })?;
+    let x: i32 = error::parse_number(line_number, line, x, "x coordinate")?;
+    let y: i32 = error::parse_number(line_number, line, y, "y coordinate")?;
+
+    Ok(Point::new(x, y))
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u32 = 15;
+
+    type Input = Input;
+    type Output1 = usize;
+    type Output2 = DistressBeacon;
+
+    const OPTIONS: &'static [&'static str] = &[
+        "row",
+        "max-coordinate",
+        "min-x",
+        "max-x",
+        "min-y",
+        "max-y",
+        "metric",
+        "search",
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(Input {
+            sensors: parse_input(input)?,
+            row_to_check: PART_1_ROW_TO_CHECK,
+            region: Region::square(PART_2_MAX_COORDINATE),
+            gap_search: GapSearch::default(),
+        })
+    }
+
+    // max-coordinate sets the whole region, which min-x, max-x, min-y, and max-y then adjust
+    fn configure(input: &mut Self::Input, options: &Options) -> Result<(), String> {
+        let parse_i32 = |name: &str| -> Result<Option<i32>, String> {
+            options
+                .get(name)
+                .map(|value| {
+                    value
+                        .parse()
+                        .map_err(|_| format!("{name} should be an integer, was {value}"))
+                })
+                .transpose()
+        };
+
+        if let Some(row_to_check) = parse_i32("row")? {
+            input.row_to_check = row_to_check;
+        }
+        if let Some(max_coordinate) = parse_i32("max-coordinate")? {
+            input.region = Region::square(max_coordinate);
+        }
+        let region = &mut input.region;
+        for (name, coordinate) in [
+            ("min-x", &mut region.min.x),
+            ("max-x", &mut region.max.x),
+            ("min-y", &mut region.min.y),
+            ("max-y", &mut region.max.y),
+        ] {
+            if let Some(value) = parse_i32(name)? {
+                *coordinate = value;
+            }
+        }
+        if region.min.x > region.max.x || region.min.y > region.max.y {
+            return Err(format!(
+                "region should not be empty, was {} to {}",
+                region.min, region.max
+            ));
+        }
+
+        if let Some(metric) = options.get("metric") {
+            let metric: Metric = metric.parse()?;
+            for sensor in &mut input.sensors {
+                *sensor = Sensor::new(sensor.coordinates, sensor.nearest_beacon, metric);
+            }
+        }
+        if let Some(gap_search) = options.get("search") {
+            input.gap_search = gap_search.parse()?;
+        }
+        Ok(())
+    }
+
+    fn part1(input: &Self::Input) -> Self::Output1 {
+        solve(&input.sensors, input.row_to_check)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Output2 {
+        solve_part_2(&input.sensors, input.region, input.gap_search)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::{Part, Solver};
+
+    const SAMPLE_INPUT: &str = include_str!("sample_input/sample15.txt");
+
+    #[test]
+    fn test_sample_input_part_1() {
+        assert_eq!(26, solve(&parse_input(SAMPLE_INPUT).unwrap(), 10));
+    }
+
+    #[test]
+    fn test_covered_intervals() {
+        let sensors = parse_input(SAMPLE_INPUT).unwrap();
+
+        let covered = covered_intervals(&sensors, 10);
+        assert_eq!(&[Interval::new(-2, 24)], covered.intervals());
+
+        // The distress beacon at x=14, y=11 is the only gap in its row
+        let covered = covered_intervals(&sensors, 11);
+        assert_eq!(
+            &[Interval::new(-3, 13), Interval::new(15, 25)],
+            covered.intervals()
+        );
+        assert_eq!(&[Interval::new(14, 14)], covered.gaps(0, 20).intervals());
+
+        // Sensors can come from the parsed puzzle input or be built directly
+        let input = Day15::parse(SAMPLE_INPUT).unwrap();
+        assert_eq!(covered, covered_intervals(input.sensors(), 11));
+        let sensor = Sensor::new(Point::new(0, 0), Point::new(2, 1), Metric::Manhattan);
+        assert_eq!(
+            &[Interval::new(-1, 1)],
+            covered_intervals(&[sensor], 2).intervals()
+        );
+    }
+
+    #[test]
+    fn test_sample_input_part_2() {
+        let sensors = parse_input(SAMPLE_INPUT).unwrap();
+        for gap_search in [GapSearch::Perimeter, GapSearch::Intersections] {
+            assert_eq!(
+                DistressBeacon::Found(Point::new(14, 11)),
+                solve_part_2(&sensors, Region::square(20), gap_search)
+            );
+            assert_eq!(
+                "56000011",
+                DistressBeacon::Found(Point::new(14, 11)).to_string()
+            );
+        }
+    }
+
+    #[test]
+    fn test_uncovered_points() {
+        let sensors = parse_input(SAMPLE_INPUT).unwrap();
+        assert_eq!(
+            vec![Point::new(14, 11)],
+            uncovered_points(&sensors, Region::square(20))
+        );
+
+        // A sensor whose range reaches every corner leaves no gaps
+        let sensors = parse_input("Sensor at x=2, y=2: closest beacon is at x=2, y=6").unwrap();
+        assert!(uncovered_points(&sensors, Region::square(4)).is_empty());
+
+        // A smaller range leaves a triangle uncovered in each corner
+        let sensors = parse_input("Sensor at x=2, y=2: closest beacon is at x=2, y=0").unwrap();
+        let expected: Vec<_> = [
+            (0, 0),
+            (1, 0),
+            (3, 0),
+            (4, 0),
+            (0, 1),
+            (4, 1),
+            (0, 3),
+            (4, 3),
+            (0, 4),
+            (1, 4),
+            (3, 4),
+            (4, 4),
+        ]
+        .into_iter()
+        .map(|(x, y)| Point::new(x, y))
+        .collect();
+        assert_eq!(expected, uncovered_points(&sensors, Region::square(4)));
+
+        // Every uncovered point is found, wherever the region is, as with checking every point
+        let sensors = parse_input(SAMPLE_INPUT).unwrap();
+        for (min, max) in [
+            ((0, 0), (25, 25)),
+            ((-10, -5), (30, 30)),
+            ((15, 0), (40, 12)),
+        ] {
+            let region = Region {
+                min: Point::new(min.0, min.1),
+                max: Point::new(max.0, max.1),
+            };
+            let expected: Vec<_> = (min.1..=max.1)
+                .flat_map(|y| (min.0..=max.0).map(move |x| Point::new(x, y)))
+                .filter(|p| is_uncovered(&sensors, p))
+                .collect();
+            assert!(expected.len() > 1);
+            assert_eq!(expected, uncovered_points(&sensors, region));
+        }
+    }
+
+    #[test]
+    fn test_options() {
+        let mut options = Options::new();
+        options.insert("row", "10");
+        options.insert("max-coordinate", "20");
+        for search in ["perimeter", "intersections"] {
+            options.insert("search", search);
+            let result = Day15.solve(SAMPLE_INPUT, &Part::ALL, &options).unwrap();
+            let answers: Vec<_> = result.parts.iter().map(|part| &part.answer).collect();
+            assert_eq!(vec!["26", "56000011"], answers);
+        }
+
+        // Shrinking the region to the row of the distress beacon still finds it
+        options.insert("min-y", "11");
+        options.insert("max-y", "11");
+        let result = Day15.solve(SAMPLE_INPUT, &[Part::Two], &options).unwrap();
+        assert_eq!("56000011", result.parts[0].answer);
+
+        options.insert("min-y", "12");
+        assert!(Day15.solve(SAMPLE_INPUT, &[Part::Two], &options).is_err());
+
+        // A region without a gap is reported rather than panicking, and part 1 is still answered
+        let mut options = Options::new();
+        options.insert("max-coordinate", "5");
+        options.insert("search", "intersections");
+        let result = Day15.solve(SAMPLE_INPUT, &Part::ALL, &options).unwrap();
+        assert_eq!("no uncovered point found", result.parts[1].answer);
+
+        let mut options = Options::new();
+        options.insert("row", "10");
+        options.insert("max-coordinate", "20");
+        options.insert("max-y", "10");
+        let result = Day15.solve(SAMPLE_INPUT, &Part::ALL, &options).unwrap();
+        let answers: Vec<_> = result.parts.iter().map(|part| &part.answer).collect();
+        assert_eq!(vec!["26", "no uncovered point found"], answers);
+
+        // A larger region takes in more uncovered points than just the distress beacon
+        let mut options = Options::new();
+        options.insert("max-coordinate", "25");
+        for search in ["perimeter", "intersections"] {
+            options.insert("search", search);
+            let result = Day15.solve(SAMPLE_INPUT, &[Part::Two], &options).unwrap();
+            assert_eq!(
+                "more than one uncovered point found",
+                result.parts[0].answer
+            );
+        }
+    }
+
+    #[test]
+    fn test_chebyshev() {
+        // Square ranges of size 2 around (2, 2) and (8, 2) leave column 5 open
+        let mut sensors = parse_input(
+            "Sensor at x=2, y=2: closest beacon is at x=4, y=4
+Sensor at x=8, y=2: closest beacon is at x=10, y=0",
+        )
+        .unwrap();
+        for sensor in &mut sensors {
+            *sensor = Sensor::new(sensor.coordinates, sensor.nearest_beacon, Metric::Chebyshev);
+        }
+
+        assert_eq!(
+            &[Interval::new(0, 4), Interval::new(6, 10)],
+            covered_intervals(&sensors, 4).intervals()
+        );
+        assert_eq!(9, solve(&sensors, 4));
+
+        let region = Region {
+            min: Point::new(0, 0),
+            max: Point::new(10, 4),
+        };
+        // The gap is the whole of column 5, though only its ends are corners
+        assert_eq!(
+            vec![Point::new(5, 0), Point::new(5, 4)],
+            gap_corners(&sensors, region)
+        );
+        assert_eq!(
+            (0..=4).map(|y| Point::new(5, y)).collect::<Vec<_>>(),
+            uncovered_points(&sensors, region)
+        );
+        assert_eq!(
+            DistressBeacon::NotUnique,
+            solve_part_2(&sensors, region, GapSearch::Intersections)
+        );
+
+        assert!(generate_candidate_points(&sensors[0], region)
+            .all(|p| p.chebyshev_distance(Point::new(2, 2)) == 3));
+    }
+}
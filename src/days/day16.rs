@@ -3,7 +3,11 @@
 //! <https://adventofcode.com/2022/day/16>
 
 use std::cmp;
-use std::collections::{HashMap, HashSet, VecDeque};
+use std::collections::{HashMap, HashSet};
+
+use crate::error::{self, ParseError};
+use crate::search;
+use crate::solution::Solution;
 
 struct Valve {
     name: String,
@@ -23,7 +27,7 @@ impl Valve {
     }
 }
 
-struct CaveGraph {
+pub struct CaveGraph {
     valves: Vec<Valve>,
     name_to_index: HashMap<String, usize>,
     valves_with_flow: Vec<usize>,
@@ -110,35 +114,38 @@ const START_VALVE_NAME: &str = "AA";
 const TURN_LIMIT_WITHOUT_ELEPHANT: u32 = 30;
 const TURN_LIMIT_WITH_ELEPHANT: u32 = 26;
 
-fn solve(input: &str) -> (u32, u32) {
-    let graph = parse_input(input);
-
-    let path_lengths = find_path_lengths(&graph, START_VALVE_NAME);
+fn solve(graph: &CaveGraph) -> u32 {
+    let path_lengths = find_path_lengths(graph, START_VALVE_NAME);
 
     let start_index = *graph.name_to_index.get(START_VALVE_NAME).unwrap();
-    let part_1_solution = find_best_path(
-        &graph,
+    find_best_path(
+        graph,
         &path_lengths,
         start_index,
         HashSet::new(),
         TURN_LIMIT_WITHOUT_ELEPHANT,
         0,
         0,
-    );
+    )
+}
+
+fn solve_part_2(graph: &CaveGraph) -> u32 {
+    let path_lengths = find_path_lengths(graph, START_VALVE_NAME);
+
+    let start_index = *graph.name_to_index.get(START_VALVE_NAME).unwrap();
+    let part_1_solution = solve(graph);
 
     let elephant_find_parameters = ElephantFindParameters::new(
-        &graph,
+        graph,
         &path_lengths,
         start_index,
         start_index,
         TURN_LIMIT_WITH_ELEPHANT,
     );
-    let part_2_solution = cmp::max(
+    cmp::max(
         part_1_solution,
         find_with_elephant(elephant_find_parameters, &mut 0),
-    );
-
-    (part_1_solution, part_2_solution)
+    )
 }
 
 // Find the distance between each pair of nodes with flow, as well as the distance from the
@@ -164,30 +171,15 @@ fn find_path_lengths(graph: &CaveGraph, start_node: &str) -> Vec<Vec<u32>> {
     result
 }
 
-// Simple BFS to find the length of the shortest path between two nodes
 fn find_shortest_path(graph: &CaveGraph, a: usize, b: usize) -> u32 {
-    let mut queue: VecDeque<(usize, u32)> = VecDeque::new();
-    queue.push_back((a, 0));
-
-    let mut visited: HashSet<usize> = HashSet::new();
-    visited.insert(a);
-
-    while !queue.is_empty() {
-        let (index, distance) = queue.pop_front().unwrap();
-
-        for &tunnel in &graph.valves[index].tunnels {
-            if tunnel == b {
-                return distance + 1;
-            }
-
-            if !visited.contains(&tunnel) {
-                visited.insert(tunnel);
-                queue.push_back((tunnel, distance + 1));
-            }
-        }
-    }
+    let path = search::bfs_to(
+        [a],
+        |&index| graph.valves[index].tunnels.iter().copied(),
+        |&index| index == b,
+    );
 
-    panic!("no path found from {a} to {b}");
+    path.expect("valves with flow are checked to be reachable while parsing")
+        .cost as u32
 }
 
 fn find_best_path(
@@ -398,39 +390,129 @@ fn compute_max_possible(parameters: &ElephantFindParameters) -> u32 {
     total
 }
 
-fn parse_input(input: &str) -> CaveGraph {
-    let mut graph = CaveGraph::new();
+fn parse_input(input: &str) -> Result<CaveGraph, ParseError> {
+    // Every valve has to be declared before tunnels can be checked against them
+    let mut lines_by_name: HashMap<&str, usize> = HashMap::new();
+    let mut valves: Vec<(usize, &str, &str, u32, Vec<&str>)> = Vec::new();
+    for (line_number, line) in error::numbered_lines(input) {
+        let split: Vec<_> = line.split(' ').collect();
+        let (name, flow_rate, tunnels) = match split.as_slice() {
+            ["Valve", name, "has", "flow", flow_rate, _, _, "to", _, tunnels @ ..]
+                if !tunnels.is_empty() =>
+            {
+                (*name, *flow_rate, tunnels)
+            }
+            _ => {
+                return Err(ParseError::new(
+                    line_number,
+                    1,
+                    "line should be in the format 'Valve <name> has flow rate=<n>; tunnels lead to valves <names>'",
+                ))
+            }
+        };
 
-    for line in input.lines() {
-        let mut split = line.split(' ').skip(1);
-        let name = split.next().expect("valve name");
-        let name = String::from(name);
+        let flow_rate = flow_rate
+            .strip_prefix("rate=")
+            .and_then(|flow_rate| flow_rate.strip_suffix(';'))
+            .ok_or_else(|| {
+                ParseError::at(
+                    line_number,
+                    line,
+                    flow_rate,
+                    "flow rate should be in the format 'rate=<n>;'",
+                )
+            })?;
+        let flow_rate = error::parse_number(line_number, line, flow_rate, "flow rate")?;
+
+        let tunnels: Vec<_> = tunnels
+            .iter()
+            .map(|tunnel| tunnel.strip_suffix(',').unwrap_or(tunnel))
+            .collect();
 
-        split.next();
-        split.next();
+        if lines_by_name.insert(name, line_number).is_some() {
+            return Err(ParseError::at(
+                line_number,
+                line,
+                name,
+                format!("valve {name} is declared more than once"),
+            ));
+        }
+        valves.push((line_number, line, name, flow_rate, tunnels));
+    }
 
-        let flow_rate = split.next().expect("flow rate");
-        let flow_rate = flow_rate["rate=".len()..flow_rate.len() - 1]
-            .parse()
-            .expect("flow rate should be an integer");
+    let mut graph = CaveGraph::new();
+    for (line_number, line, name, flow_rate, tunnels) in &valves {
+        if let Some(tunnel) = tunnels
+            .iter()
+            .find(|tunnel| !lines_by_name.contains_key(*tunnel))
+        {
+            return Err(ParseError::at(
+                *line_number,
+                line,
+                tunnel,
+                format!("no valve named {tunnel}"),
+            ));
+        }
+        graph.add_valve(name, *flow_rate, tunnels);
+    }
 
-        let tunnels: Vec<_> = split
-            .skip(4)
-            .map(|tunnel| tunnel.strip_suffix(',').unwrap_or(tunnel))
-            .collect();
+    if !graph.name_to_index.contains_key(START_VALVE_NAME) {
+        return Err(ParseError::new(
+            1,
+            1,
+            format!("input should contain the start valve {START_VALVE_NAME}"),
+        ));
+    }
 
-        graph.add_valve(&name, flow_rate, &tunnels);
+    // Valves are only ever opened after walking from the start or from another valve with flow
+    for from in graph
+        .valves
+        .iter()
+        .filter(|valve| valve.name == START_VALVE_NAME || valve.flow_rate > 0)
+    {
+        let reachable = search::bfs([from.index], |&index| {
+            graph.valves[index].tunnels.iter().copied()
+        });
+        if let Some(to) = graph
+            .valves
+            .iter()
+            .find(|to| to.flow_rate > 0 && reachable.distance(&to.index).is_none())
+        {
+            let line_number = lines_by_name[to.name.as_str()];
+            return Err(ParseError::new(
+                line_number,
+                1,
+                format!(
+                    "valve {} cannot be reached from valve {}",
+                    to.name, from.name
+                ),
+            ));
+        }
     }
 
-    graph
+    Ok(graph)
 }
 
-fn main() {
-    let input = advent_of_code_2022::read_input().expect("unable to read input file");
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u32 = 16;
 
-    let (solution1, solution2) = solve(&input);
-    println!("{solution1}");
-    println!("{solution2}");
+    type Input = CaveGraph;
+    type Output1 = u32;
+    type Output2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Output1 {
+        solve(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Output2 {
+        solve_part_2(input)
+    }
 }
 
 #[cfg(test)]
@@ -441,13 +523,42 @@ mod tests {
 
     #[test]
     fn test_sample_input_part_1() {
-        let (solution1, _) = solve(SAMPLE_INPUT);
-        assert_eq!(1651, solution1);
+        assert_eq!(1651, solve(&parse_input(SAMPLE_INPUT).unwrap()));
     }
 
     #[test]
     fn test_sample_input_part_2() {
-        let (_, solution2) = solve(SAMPLE_INPUT);
-        assert_eq!(1707, solution2);
+        assert_eq!(1707, solve_part_2(&parse_input(SAMPLE_INPUT).unwrap()));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        // AA is only ever a tunnel target, so it is not declared
+        assert_eq!(
+            Err(ParseError::new(1, 49, "no valve named AA")),
+            parse_input("Valve BB has flow rate=1; tunnel leads to valve AA").map(|_| ())
+        );
+
+        let input = format!("{SAMPLE_INPUT}Valve BB has flow rate=3; tunnel leads to valve AA");
+        assert_eq!(
+            Err(ParseError::new(
+                11,
+                7,
+                "valve BB is declared more than once"
+            )),
+            parse_input(&input).map(|_| ())
+        );
+
+        // Tunnels only lead one way, and none of them lead from AA to BB
+        let input = "Valve AA has flow rate=0; tunnel leads to valve AA
+Valve BB has flow rate=5; tunnel leads to valve AA";
+        assert_eq!(
+            Err(ParseError::new(
+                2,
+                1,
+                "valve BB cannot be reached from valve AA"
+            )),
+            parse_input(input).map(|_| ())
+        );
     }
 }
@@ -5,17 +5,11 @@
 use std::cmp;
 use std::collections::HashMap;
 
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
-struct Point {
-    x: i64,
-    y: i64,
-}
+use crate::error::ParseError;
+use crate::point::Point2;
+use crate::solution::Solution;
 
-impl Point {
-    fn new(x: i64, y: i64) -> Self {
-        Self { x, y }
-    }
-}
+type Point = Point2<i64>;
 
 #[derive(Debug, Clone)]
 struct Tetronimo {
@@ -169,9 +163,8 @@ const PART_1_TO_DROP: usize = 2022;
 
 const PART_2_TO_DROP: i64 = 1_000_000_000_000;
 
-fn solve(input: &str) -> i64 {
-    let line = input.lines().next().expect("input should not be empty");
-    let mut jet_iter = repeating_jet_iterator(line);
+fn solve(jets: &[i64]) -> i64 {
+    let mut jet_iter = repeating_jet_iterator(jets);
 
     let mut tetronimo_type_iter = repeating_tetronimo_type_iterator();
 
@@ -193,9 +186,8 @@ fn solve(input: &str) -> i64 {
     tower_height
 }
 
-fn solve_part_2(input: &str) -> i64 {
-    let line = input.lines().next().expect("input should not be empty");
-    let mut jet_iter = repeating_jet_iterator(line).peekable();
+fn solve_part_2(jets: &[i64]) -> i64 {
+    let mut jet_iter = repeating_jet_iterator(jets).peekable();
 
     let mut tetronimo_type_iter = repeating_tetronimo_type_iterator();
 
@@ -271,7 +263,7 @@ fn try_move(
     let mut new_points = Vec::with_capacity(tetronimo.points.len());
 
     for point in &tetronimo.points {
-        let new_point = Point::new(point.x + dx, point.y + dy);
+        let new_point = *point + Point::new(dx, dy);
 
         if new_point.x < 0
             || new_point.x >= CHAMBER_WIDTH
@@ -286,15 +278,8 @@ fn try_move(
     Some(Tetronimo { points: new_points })
 }
 
-fn repeating_jet_iterator(line: &str) -> impl Iterator<Item = (usize, i64)> + '_ {
-    line.chars()
-        .map(|c| match c {
-            '<' => -1,
-            '>' => 1,
-            _ => panic!("unexpected character in jet stream: {c}"),
-        })
-        .enumerate()
-        .cycle()
+fn repeating_jet_iterator(jets: &[i64]) -> impl Iterator<Item = (usize, i64)> + '_ {
+    jets.iter().copied().enumerate().cycle()
 }
 
 fn repeating_tetronimo_type_iterator() -> impl Iterator<Item = TetronimoType> {
@@ -309,14 +294,47 @@ fn repeating_tetronimo_type_iterator() -> impl Iterator<Item = TetronimoType> {
     .cycle()
 }
 
-fn main() {
-    let input = advent_of_code_2022::read_input().expect("unable to read input file");
+fn parse_input(input: &str) -> Result<Vec<i64>, ParseError> {
+    let line = input
+        .lines()
+        .next()
+        .filter(|line| !line.is_empty())
+        .ok_or_else(|| ParseError::new(1, 1, "input should not be empty"))?;
+
+    line.chars()
+        .enumerate()
+        .map(|(j, c)| match c {
+            '<' => Ok(-1),
+            '>' => Ok(1),
+            _ => Err(ParseError::new(
+                1,
+                j + 1,
+                format!("unexpected character in jet stream: {c}"),
+            )),
+        })
+        .collect()
+}
+
+pub struct Day17;
 
-    let solution1 = solve(&input);
-    println!("{solution1}");
+impl Solution for Day17 {
+    const DAY: u32 = 17;
 
-    let solution2 = solve_part_2(&input);
-    println!("{solution2}");
+    type Input = Vec<i64>;
+    type Output1 = i64;
+    type Output2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Output1 {
+        solve(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Output2 {
+        solve_part_2(input)
+    }
 }
 
 #[cfg(test)]
@@ -327,11 +345,14 @@ mod tests {
 
     #[test]
     fn test_sample_input_part_1() {
-        assert_eq!(3068, solve(SAMPLE_INPUT));
+        assert_eq!(3068, solve(&parse_input(SAMPLE_INPUT).unwrap()));
     }
 
     #[test]
     fn test_sample_input_part_2() {
-        assert_eq!(1514285714288, solve_part_2(SAMPLE_INPUT));
+        assert_eq!(
+            1514285714288,
+            solve_part_2(&parse_input(SAMPLE_INPUT).unwrap())
+        );
     }
 }
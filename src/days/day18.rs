@@ -0,0 +1,146 @@
+//! Day 18: Boiling Boulders
+//!
+//! <https://adventofcode.com/2022/day/18>
+
+use std::cmp;
+use std::collections::HashSet;
+
+use crate::error::{self, ParseError};
+use crate::point::Point3;
+use crate::search::{self, SearchTree};
+use crate::solution::Solution;
+
+type Cube = Point3<i32>;
+
+fn solve(cubes: &[Cube]) -> usize {
+    let cubes_set: HashSet<_> = cubes.iter().copied().collect();
+
+    cubes
+        .iter()
+        .map(|cube| {
+            cube.neighbours6()
+                .filter(|neighbour| !cubes_set.contains(neighbour))
+                .count()
+        })
+        .sum()
+}
+
+fn solve_part_2(cubes: &[Cube]) -> usize {
+    let cubes_set: HashSet<_> = cubes.iter().copied().collect();
+
+    let (min_x, min_y, min_z) = get_minimums(cubes);
+    let (max_x, max_y, max_z) = get_maximums(cubes);
+
+    // Pad the bounding box by 1 in each direction so that water can flow all the way around
+    let min = Cube::new(min_x - 1, min_y - 1, min_z - 1);
+    let max = Cube::new(max_x + 1, max_y + 1, max_z + 1);
+    let water = floodfill_3d(&cubes_set, min, max);
+
+    cubes
+        .iter()
+        .map(|cube| {
+            cube.neighbours6()
+                .filter(|neighbour| water.contains(neighbour))
+                .count()
+        })
+        .sum()
+}
+
+// Every non-cube position reachable from min without leaving the box between min and max
+fn floodfill_3d(cubes: &HashSet<Cube>, min: Cube, max: Cube) -> SearchTree<Cube, usize> {
+    let in_bounds = move |cube: &Cube| {
+        (min.x..=max.x).contains(&cube.x)
+            && (min.y..=max.y).contains(&cube.y)
+            && (min.z..=max.z).contains(&cube.z)
+    };
+
+    search::bfs([min], |cube| {
+        cube.neighbours6()
+            .filter(|neighbour| in_bounds(neighbour) && !cubes.contains(neighbour))
+            .collect::<Vec<_>>()
+    })
+}
+
+fn get_minimums(cubes: &[Cube]) -> (i32, i32, i32) {
+    cubes.iter().fold(
+        (i32::MAX, i32::MAX, i32::MAX),
+        |(min_x, min_y, min_z), cube| {
+            (
+                cmp::min(min_x, cube.x),
+                cmp::min(min_y, cube.y),
+                cmp::min(min_z, cube.z),
+            )
+        },
+    )
+}
+
+fn get_maximums(cubes: &[Cube]) -> (i32, i32, i32) {
+    cubes.iter().fold(
+        (i32::MIN, i32::MIN, i32::MIN),
+        |(max_x, max_y, max_z), cube| {
+            (
+                cmp::max(max_x, cube.x),
+                cmp::max(max_y, cube.y),
+                cmp::max(max_z, cube.z),
+            )
+        },
+    )
+}
+
+fn parse_input(input: &str) -> Result<Vec<Cube>, ParseError> {
+    error::numbered_lines(input)
+        .map(|(line_number, line)| {
+            let split: Vec<i32> = line
+                .split(',')
+                .map(|s| error::parse_number(line_number, line, s, "coordinate"))
+                .collect::<Result<_, _>>()?;
+            match split.as_slice() {
+                &[x, y, z] => Ok(Cube::new(x, y, z)),
+                _ => Err(ParseError::new(
+                    line_number,
+                    1,
+                    format!("cube should have 3 coordinates, had {}", split.len()),
+                )),
+            }
+        })
+        .collect()
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u32 = 18;
+
+    type Input = Vec<Cube>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Output1 {
+        solve(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Output2 {
+        solve_part_2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE_INPUT: &str = include_str!("sample_input/sample18.txt");
+
+    #[test]
+    fn test_sample_input_part_1() {
+        assert_eq!(64, solve(&parse_input(SAMPLE_INPUT).unwrap()));
+    }
+
+    #[test]
+    fn test_sample_input_part_2() {
+        assert_eq!(58, solve_part_2(&parse_input(SAMPLE_INPUT).unwrap()));
+    }
+}
@@ -5,8 +5,11 @@
 use std::cmp;
 use std::collections::HashMap;
 
+use crate::error::{self, ParseError};
+use crate::solution::Solution;
+
 #[derive(Debug)]
-struct Blueprint {
+pub struct Blueprint {
     ore_robot_ore_cost: u32,
     clay_robot_ore_cost: u32,
     obsidian_robot_ore_cost: u32,
@@ -54,23 +57,19 @@ impl SearchState {
     }
 }
 
-fn solve(input: &str) -> u32 {
-    let blueprints = parse_input(input);
-
+fn solve(blueprints: &[Blueprint]) -> u32 {
     blueprints
-        .into_iter()
+        .iter()
         .enumerate()
-        .map(|(i, blueprint)| find_max_for_blueprint(&blueprint, 24) * ((i + 1) as u32))
+        .map(|(i, blueprint)| find_max_for_blueprint(blueprint, 24) * ((i + 1) as u32))
         .sum()
 }
 
-fn solve_part_2(input: &str) -> u32 {
-    let blueprints = parse_input(input);
-
+fn solve_part_2(blueprints: &[Blueprint]) -> u32 {
     let first_blueprints = if blueprints.len() >= 3 {
         &blueprints[..3]
     } else {
-        &blueprints[..]
+        blueprints
     };
 
     first_blueprints
@@ -279,61 +278,53 @@ fn estimate_max_possible(blueprint: &Blueprint, state: &SearchState) -> u32 {
     geode
 }
 
-fn parse_input(input: &str) -> Vec<Blueprint> {
-    input
-        .lines()
-        .map(|line| {
-            let mut split = line.split(' ').skip(6);
-
-            let ore_robot_ore_cost = split.next().unwrap().parse().expect("ore robot ore cost");
-
-            let mut split = split.skip(5);
-            let clay_robot_ore_cost = split.next().unwrap().parse().expect("clay robot ore cost");
-
-            let mut split = split.skip(5);
-            let obsidian_robot_ore_cost = split
-                .next()
-                .unwrap()
-                .parse()
-                .expect("obsidian robot ore cost");
-
-            let mut split = split.skip(2);
-            let obsidian_robot_clay_cost = split
-                .next()
-                .unwrap()
-                .parse()
-                .expect("obsidian robot clay cost");
-
-            let mut split = split.skip(5);
-            let geode_robot_ore_cost = split.next().unwrap().parse().expect("geode robot ore cost");
-
-            let mut split = split.skip(2);
-            let geode_robot_obsidian_cost = split
-                .next()
-                .unwrap()
-                .parse()
-                .expect("geode robot obsidian cost");
-
-            Blueprint {
-                ore_robot_ore_cost,
-                clay_robot_ore_cost,
-                obsidian_robot_ore_cost,
-                obsidian_robot_clay_cost,
-                geode_robot_ore_cost,
-                geode_robot_obsidian_cost,
+fn parse_input(input: &str) -> Result<Vec<Blueprint>, ParseError> {
+    error::numbered_lines(input)
+        .map(|(line_number, line)| {
+            let split: Vec<_> = line.split(' ').collect();
+            if split.len() != 32 {
+                return Err(ParseError::new(
+                    line_number,
+                    1,
+                    format!("blueprint should have 32 words, had {}", split.len()),
+                ));
             }
+
+            let cost =
+                |i: usize, what: &str| error::parse_number(line_number, line, split[i], what);
+
+            Ok(Blueprint {
+                ore_robot_ore_cost: cost(6, "ore robot ore cost")?,
+                clay_robot_ore_cost: cost(12, "clay robot ore cost")?,
+                obsidian_robot_ore_cost: cost(18, "obsidian robot ore cost")?,
+                obsidian_robot_clay_cost: cost(21, "obsidian robot clay cost")?,
+                geode_robot_ore_cost: cost(27, "geode robot ore cost")?,
+                geode_robot_obsidian_cost: cost(30, "geode robot obsidian cost")?,
+            })
         })
         .collect()
 }
 
-fn main() {
-    let input = advent_of_code_2022::read_input().expect("unable to read input file");
+pub struct Day19;
 
-    let solution1 = solve(&input);
-    println!("{solution1}");
+impl Solution for Day19 {
+    const DAY: u32 = 19;
 
-    let solution2 = solve_part_2(&input);
-    println!("{solution2}");
+    type Input = Vec<Blueprint>;
+    type Output1 = u32;
+    type Output2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Output1 {
+        solve(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Output2 {
+        solve_part_2(input)
+    }
 }
 
 #[cfg(test)]
@@ -344,11 +335,11 @@ mod tests {
 
     #[test]
     fn test_sample_input_part_1() {
-        assert_eq!(33, solve(SAMPLE_INPUT));
+        assert_eq!(33, solve(&parse_input(SAMPLE_INPUT).unwrap()));
     }
 
     #[test]
     fn test_sample_input_part_2() {
-        assert_eq!(3472, solve_part_2(SAMPLE_INPUT));
+        assert_eq!(3472, solve_part_2(&parse_input(SAMPLE_INPUT).unwrap()));
     }
 }
@@ -0,0 +1,209 @@
+//! Day 2: Rock Paper Scissors
+//!
+//! <https://adventofcode.com/2022/day/2>
+
+use crate::error::{self, ParseError};
+use crate::solution::Solution;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Play {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+impl Play {
+    fn from_char(c: char) -> Option<Play> {
+        match c {
+            'A' => Some(Self::Rock),
+            'B' => Some(Self::Paper),
+            'C' => Some(Self::Scissors),
+            _ => None,
+        }
+    }
+
+    fn point_value(&self) -> i32 {
+        match self {
+            Self::Rock => 1,
+            Self::Paper => 2,
+            Self::Scissors => 3,
+        }
+    }
+
+    fn get_winning_play(&self) -> Play {
+        match self {
+            Self::Rock => Self::Paper,
+            Self::Paper => Self::Scissors,
+            Self::Scissors => Self::Rock,
+        }
+    }
+
+    fn get_losing_play(&self) -> Play {
+        match self {
+            Self::Rock => Self::Scissors,
+            Self::Paper => Self::Rock,
+            Self::Scissors => Self::Paper,
+        }
+    }
+
+    fn beats(&self, other: &Play) -> bool {
+        *other == self.get_losing_play()
+    }
+}
+
+// The second column of the strategy guide, which part 1 reads as a play and part 2 as an outcome
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Response {
+    X,
+    Y,
+    Z,
+}
+
+impl Response {
+    fn from_char(c: char) -> Option<Response> {
+        match c {
+            'X' => Some(Self::X),
+            'Y' => Some(Self::Y),
+            'Z' => Some(Self::Z),
+            _ => None,
+        }
+    }
+
+    fn as_play(self) -> Play {
+        match self {
+            Self::X => Play::Rock,
+            Self::Y => Play::Paper,
+            Self::Z => Play::Scissors,
+        }
+    }
+}
+
+const WIN_SCORE: i32 = 6;
+const DRAW_SCORE: i32 = 3;
+const LOSE_SCORE: i32 = 0;
+
+fn solve(rounds: &[(Play, Response)]) -> i32 {
+    rounds
+        .iter()
+        .map(|(opponent_play, response)| {
+            let your_play = response.as_play();
+
+            let game_score = if *opponent_play == your_play {
+                DRAW_SCORE
+            } else if your_play.beats(opponent_play) {
+                WIN_SCORE
+            } else {
+                LOSE_SCORE
+            };
+
+            game_score + your_play.point_value()
+        })
+        .sum()
+}
+
+fn solve_part_2(rounds: &[(Play, Response)]) -> i32 {
+    rounds
+        .iter()
+        .map(|(opponent_play, response)| match response {
+            Response::X => LOSE_SCORE + opponent_play.get_losing_play().point_value(),
+            Response::Y => DRAW_SCORE + opponent_play.point_value(),
+            Response::Z => WIN_SCORE + opponent_play.get_winning_play().point_value(),
+        })
+        .sum()
+}
+
+fn parse_input(input: &str) -> Result<Vec<(Play, Response)>, ParseError> {
+    error::numbered_lines(input)
+        .map(|(line_number, line)| {
+            let chars: Vec<char> = line.chars().collect();
+            let [first_char, ' ', last_char] = chars[..] else {
+                return Err(ParseError::new(
+                    line_number,
+                    1,
+                    "line should be in the format '<A-C> <X-Z>'",
+                ));
+            };
+
+            let opponent_play = Play::from_char(first_char).ok_or_else(|| {
+                ParseError::new(
+                    line_number,
+                    1,
+                    format!("opponent play should be A-C, was '{first_char}'"),
+                )
+            })?;
+
+            let response = Response::from_char(last_char).ok_or_else(|| {
+                ParseError::new(
+                    line_number,
+                    3,
+                    format!("last column should be X-Z, was '{last_char}'"),
+                )
+            })?;
+
+            Ok((opponent_play, response))
+        })
+        .collect()
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u32 = 2;
+
+    type Input = Vec<(Play, Response)>;
+    type Output1 = i32;
+    type Output2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Output1 {
+        solve(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Output2 {
+        solve_part_2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE_INPUT: &str = include_str!("sample_input/sample2.txt");
+
+    #[test]
+    fn test_sample_input_part_1() {
+        assert_eq!(15, solve(&parse_input(SAMPLE_INPUT).unwrap()));
+    }
+
+    #[test]
+    fn test_sample_input_part_2() {
+        assert_eq!(12, solve_part_2(&parse_input(SAMPLE_INPUT).unwrap()));
+    }
+
+    #[test]
+    fn test_invalid_play() {
+        assert_eq!(
+            Err(ParseError::new(2, 3, "last column should be X-Z, was 'W'")),
+            parse_input("A Y\nB W\n")
+        );
+        assert_eq!(
+            Err(ParseError::new(
+                1,
+                1,
+                "line should be in the format '<A-C> <X-Z>'"
+            )),
+            parse_input("AZ")
+        );
+        assert_eq!(
+            Err(ParseError::new(
+                1,
+                1,
+                "opponent play should be A-C, was 'X'"
+            )),
+            parse_input("X A")
+        );
+    }
+}
@@ -2,6 +2,9 @@
 //!
 //! <https://adventofcode.com/2022/day/20>
 
+use crate::error::{self, ParseError};
+use crate::solution::Solution;
+
 struct Node {
     value: i64,
     original_index: usize,
@@ -67,11 +70,8 @@ impl NumberList {
     }
 }
 
-fn solve(input: &str, multiplier: i64, iterations: usize) -> i64 {
-    let numbers: Vec<_> = parse_input(input)
-        .into_iter()
-        .map(|n| n * multiplier)
-        .collect();
+fn solve(numbers: &[i64], multiplier: i64, iterations: usize) -> i64 {
+    let numbers: Vec<_> = numbers.iter().map(|n| n * multiplier).collect();
     let mut list = NumberList::from_vec(numbers);
 
     for _ in 0..iterations {
@@ -83,32 +83,50 @@ fn solve(input: &str, multiplier: i64, iterations: usize) -> i64 {
     list.get(1000) + list.get(2000) + list.get(3000)
 }
 
-fn solve_part_1(input: &str) -> i64 {
-    solve(input, 1, 1)
+fn solve_part_1(numbers: &[i64]) -> i64 {
+    solve(numbers, 1, 1)
 }
 
-fn solve_part_2(input: &str) -> i64 {
-    solve(input, 811589153, 10)
+fn solve_part_2(numbers: &[i64]) -> i64 {
+    solve(numbers, 811589153, 10)
 }
 
-fn parse_input(input: &str) -> Vec<i64> {
-    input
-        .lines()
-        .map(|line| {
-            line.parse::<i64>()
-                .expect("each line should contain an integer")
-        })
-        .collect()
+fn parse_input(input: &str) -> Result<Vec<i64>, ParseError> {
+    let numbers: Vec<i64> = error::numbered_lines(input)
+        .map(|(line_number, line)| error::parse_number(line_number, line, line, "number"))
+        .collect::<Result<_, _>>()?;
+
+    // Moves wrap around every len - 1 places, which needs something to move past
+    if numbers.len() < 2 {
+        return Err(ParseError::new(1, 1, "list should have at least 2 numbers"));
+    }
+    if !numbers.contains(&0) {
+        return Err(ParseError::new(1, 1, "list should contain 0"));
+    }
+
+    Ok(numbers)
 }
 
-fn main() {
-    let input = advent_of_code_2022::read_input().expect("unable to read input file");
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u32 = 20;
+
+    type Input = Vec<i64>;
+    type Output1 = i64;
+    type Output2 = i64;
 
-    let solution1 = solve_part_1(&input);
-    println!("{solution1}");
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Output1 {
+        solve_part_1(input)
+    }
 
-    let solution2 = solve_part_2(&input);
-    println!("{solution2}");
+    fn part2(input: &Self::Input) -> Self::Output2 {
+        solve_part_2(input)
+    }
 }
 
 #[cfg(test)]
@@ -119,11 +137,34 @@ mod tests {
 
     #[test]
     fn test_sample_input_part_1() {
-        assert_eq!(3, solve_part_1(SAMPLE_INPUT));
+        assert_eq!(3, solve_part_1(&parse_input(SAMPLE_INPUT).unwrap()));
     }
 
     #[test]
     fn test_sample_input_part_2() {
-        assert_eq!(1623178306, solve_part_2(SAMPLE_INPUT));
+        assert_eq!(
+            1623178306,
+            solve_part_2(&parse_input(SAMPLE_INPUT).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            Err(ParseError::new(1, 1, "list should have at least 2 numbers")),
+            parse_input("0")
+        );
+        assert_eq!(
+            Err(ParseError::new(1, 1, "list should contain 0")),
+            parse_input("1\n2")
+        );
+        assert_eq!(
+            Err(ParseError::new(
+                2,
+                1,
+                "number should be an integer, was 'x'"
+            )),
+            parse_input("0\nx")
+        );
     }
 }
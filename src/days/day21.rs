@@ -0,0 +1,361 @@
+//! Day 21: Monkey Math
+//!
+//! <https://adventofcode.com/2022/day/21>
+
+use std::collections::{HashMap, HashSet};
+
+use crate::error::{self, ParseError};
+use crate::solution::Solution;
+
+#[derive(Debug)]
+pub enum Monkey {
+    Constant {
+        name: String,
+        n: i64,
+    },
+    Add {
+        name: String,
+        a: Box<Monkey>,
+        b: Box<Monkey>,
+    },
+    Subtract {
+        name: String,
+        a: Box<Monkey>,
+        b: Box<Monkey>,
+    },
+    Multiply {
+        name: String,
+        a: Box<Monkey>,
+        b: Box<Monkey>,
+    },
+    Divide {
+        name: String,
+        a: Box<Monkey>,
+        b: Box<Monkey>,
+    },
+}
+
+impl Monkey {
+    // all_lines maps each monkey name to its line number, full line, and the job after the name.
+    // resolving holds the monkeys whose jobs are being built, so that a cycle is reported instead
+    // of recursing forever.
+    fn from_line<'a>(
+        name: &'a str,
+        all_lines: &HashMap<&'a str, (usize, &'a str, &'a str)>,
+        resolving: &mut HashSet<&'a str>,
+    ) -> Result<Self, ParseError> {
+        let &(line_number, full_line, line) = all_lines.get(name).unwrap();
+        resolving.insert(name);
+        let key = name;
+        let name = String::from(name);
+
+        let mut child = |child_name: &'a str| {
+            if resolving.contains(child_name) {
+                return Err(ParseError::at(
+                    line_number,
+                    full_line,
+                    child_name,
+                    format!("monkey {child_name} depends on its own result"),
+                ));
+            }
+            match all_lines.get(child_name) {
+                Some(_) => Self::from_line(child_name, all_lines, resolving).map(Box::new),
+                None => Err(ParseError::at(
+                    line_number,
+                    full_line,
+                    child_name,
+                    format!("no monkey named {child_name}"),
+                )),
+            }
+        };
+
+        let split: Vec<_> = line.split(' ').collect();
+        let monkey = match split.as_slice() {
+            [n] => Ok(Self::Constant {
+                name,
+                n: error::parse_number(line_number, full_line, n, "single word")?,
+            }),
+            [a, op, b] => {
+                let a = child(a)?;
+                let b = child(b)?;
+
+                match *op {
+                    "+" => Ok(Self::Add { name, a, b }),
+                    "-" => Ok(Self::Subtract { name, a, b }),
+                    "*" => Ok(Self::Multiply { name, a, b }),
+                    "/" => Ok(Self::Divide { name, a, b }),
+                    _ => Err(ParseError::at(
+                        line_number,
+                        full_line,
+                        op,
+                        format!("unexpected operator: {op}"),
+                    )),
+                }
+            }
+            _ => Err(ParseError::at(
+                line_number,
+                full_line,
+                line,
+                format!("unexpected line format: {line}"),
+            )),
+        };
+
+        // A monkey may be used by several others as long as it does not depend on itself
+        resolving.remove(key);
+        monkey
+    }
+
+    // Errors if a monkey divides by zero or a result does not fit in an i64
+    fn evaluate(&self) -> Result<i64, String> {
+        match self {
+            Self::Constant { n, .. } => Ok(*n),
+            Self::Add { name, a, b } => checked(name, a.evaluate()?.checked_add(b.evaluate()?)),
+            Self::Subtract { name, a, b } => {
+                checked(name, a.evaluate()?.checked_sub(b.evaluate()?))
+            }
+            Self::Multiply { name, a, b } => {
+                checked(name, a.evaluate()?.checked_mul(b.evaluate()?))
+            }
+            Self::Divide { name, a, b } => checked(name, a.evaluate()?.checked_div(b.evaluate()?)),
+        }
+    }
+
+    fn find_human_path<'a>(&'a self, human_path: &mut HashSet<&'a str>) -> bool {
+        if self.get_name() == "humn" {
+            human_path.insert("humn");
+            return true;
+        }
+
+        match self {
+            Self::Constant { .. } => false,
+            Self::Add { name, a, b }
+            | Self::Subtract { name, a, b }
+            | Self::Multiply { name, a, b }
+            | Self::Divide { name, a, b } => {
+                if a.find_human_path(human_path) || b.find_human_path(human_path) {
+                    human_path.insert(name.as_str());
+                    true
+                } else {
+                    false
+                }
+            }
+        }
+    }
+
+    fn solve_for_human(
+        &self,
+        current_value: i64,
+        human_path: &HashSet<&str>,
+    ) -> Result<i64, String> {
+        match self {
+            Self::Constant { name, n } => {
+                if human_path.contains(name.as_str()) {
+                    Ok(current_value)
+                } else {
+                    Ok(*n)
+                }
+            }
+            Self::Add { name, a, b } => {
+                if human_path.contains(a.get_name()) {
+                    let value = checked(name, current_value.checked_sub(b.evaluate()?))?;
+                    a.solve_for_human(value, human_path)
+                } else {
+                    let value = checked(name, current_value.checked_sub(a.evaluate()?))?;
+                    b.solve_for_human(value, human_path)
+                }
+            }
+            Self::Subtract { name, a, b } => {
+                if human_path.contains(a.get_name()) {
+                    let value = checked(name, current_value.checked_add(b.evaluate()?))?;
+                    a.solve_for_human(value, human_path)
+                } else {
+                    let value = checked(name, a.evaluate()?.checked_sub(current_value))?;
+                    b.solve_for_human(value, human_path)
+                }
+            }
+            Self::Multiply { name, a, b } => {
+                if human_path.contains(a.get_name()) {
+                    let value = checked(name, current_value.checked_div(b.evaluate()?))?;
+                    a.solve_for_human(value, human_path)
+                } else {
+                    let value = checked(name, current_value.checked_div(a.evaluate()?))?;
+                    b.solve_for_human(value, human_path)
+                }
+            }
+            Self::Divide { name, a, b } => {
+                if human_path.contains(a.get_name()) {
+                    let value = checked(name, current_value.checked_mul(b.evaluate()?))?;
+                    a.solve_for_human(value, human_path)
+                } else {
+                    let value = checked(name, a.evaluate()?.checked_div(current_value))?;
+                    b.solve_for_human(value, human_path)
+                }
+            }
+        }
+    }
+
+    fn get_name(&self) -> &str {
+        match self {
+            Self::Constant { name, .. }
+            | Self::Add { name, .. }
+            | Self::Subtract { name, .. }
+            | Self::Multiply { name, .. }
+            | Self::Divide { name, .. } => name,
+        }
+    }
+}
+
+// Turns a failed checked operation by the named monkey into an error
+fn checked(name: &str, result: Option<i64>) -> Result<i64, String> {
+    result.ok_or_else(|| format!("monkey {name} divides by zero or overflows"))
+}
+
+fn solve(root_monkey: &Monkey) -> Result<i64, String> {
+    root_monkey.evaluate()
+}
+
+fn solve_part_2(root_monkey: &Monkey) -> Result<i64, String> {
+    let mut human_path: HashSet<&str> = HashSet::new();
+    root_monkey.find_human_path(&mut human_path);
+
+    match root_monkey {
+        Monkey::Add { a, b, .. }
+        | Monkey::Subtract { a, b, .. }
+        | Monkey::Multiply { a, b, .. }
+        | Monkey::Divide { a, b, .. } => {
+            if human_path.contains(a.get_name()) {
+                a.solve_for_human(b.evaluate()?, &human_path)
+            } else {
+                b.solve_for_human(a.evaluate()?, &human_path)
+            }
+        }
+        Monkey::Constant { .. } => unreachable!("root should have been checked while parsing"),
+    }
+}
+
+fn parse_input(input: &str) -> Result<Monkey, ParseError> {
+    let lines_by_name: HashMap<_, _> = error::numbered_lines(input)
+        .map(|(line_number, line)| {
+            let (name, rest_of_line) = line
+                .split_once(": ")
+                .ok_or_else(|| ParseError::new(line_number, 1, "every line should have a ': '"))?;
+
+            Ok((name, (line_number, line, rest_of_line)))
+        })
+        .collect::<Result<_, _>>()?;
+
+    let Some(&(root_line_number, _, _)) = lines_by_name.get("root") else {
+        return Err(ParseError::new(1, 1, "input should have a root line"));
+    };
+    let root = Monkey::from_line("root", &lines_by_name, &mut HashSet::new())?;
+
+    // Part 2 compares the two numbers root waits for and solves for the one humn is part of
+    if let Monkey::Constant { .. } = root {
+        return Err(ParseError::new(
+            root_line_number,
+            1,
+            "root should wait for two other monkeys",
+        ));
+    }
+    if !root.find_human_path(&mut HashSet::new()) {
+        return Err(ParseError::new(
+            root_line_number,
+            1,
+            "root should depend on a monkey named humn",
+        ));
+    }
+
+    Ok(root)
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    const DAY: u32 = 21;
+
+    type Input = Monkey;
+    type Output1 = String;
+    type Output2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Output1 {
+        solve(input).map_or_else(|err| err, |n| n.to_string())
+    }
+
+    fn part2(input: &Self::Input) -> Self::Output2 {
+        solve_part_2(input).map_or_else(|err| err, |n| n.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::{Options, Part, Solver};
+
+    const SAMPLE_INPUT: &str = include_str!("sample_input/sample21.txt");
+
+    #[test]
+    fn test_sample_input_part_1() {
+        assert_eq!(Ok(152), solve(&parse_input(SAMPLE_INPUT).unwrap()));
+    }
+
+    #[test]
+    fn test_cycle() {
+        assert_eq!(
+            Err(ParseError::new(
+                2,
+                7,
+                "monkey root depends on its own result"
+            )),
+            parse_input("root: abcd + efgh\nefgh: root * 2\nabcd: 3").map(|_| ())
+        );
+
+        // Monkeys used more than once are not cycles
+        let monkey = parse_input("root: abcd + abcd\nabcd: humn * humn\nhumn: 3").unwrap();
+        assert_eq!(Ok(18), monkey.evaluate());
+    }
+
+    #[test]
+    fn test_invalid_monkeys() {
+        assert_eq!(
+            Err(ParseError::new(
+                1,
+                1,
+                "root should wait for two other monkeys"
+            )),
+            parse_input("root: 5\nhumn: 1").map(|_| ())
+        );
+        assert_eq!(
+            Err(ParseError::new(
+                1,
+                1,
+                "root should depend on a monkey named humn"
+            )),
+            parse_input("root: abcd + efgh\nabcd: 1\nefgh: 2\nhumn: 3").map(|_| ())
+        );
+
+        let answers = |input: &str| -> Vec<String> {
+            let result = Day21.solve(input, &Part::ALL, &Options::new()).unwrap();
+            result.parts.into_iter().map(|part| part.answer).collect()
+        };
+        let input = "root: abcd / efgh\nabcd: humn + efgh\nefgh: 0\nhumn: 3";
+        assert_eq!(
+            "monkey root divides by zero or overflows",
+            answers(input)[0]
+        );
+        // Working back to humn has to divide by efgh
+        let input = "root: abcd + efgh\nabcd: humn * efgh\nefgh: 0\nhumn: 3";
+        assert_eq!(
+            vec!["0", "monkey abcd divides by zero or overflows"],
+            answers(input)
+        );
+    }
+
+    #[test]
+    fn test_sample_input_part_2() {
+        assert_eq!(Ok(301), solve_part_2(&parse_input(SAMPLE_INPUT).unwrap()));
+    }
+}
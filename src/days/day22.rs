@@ -2,6 +2,13 @@
 //!
 //! <https://adventofcode.com/2022/day/22>
 
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, HashSet};
+
+use crate::error::{self, ParseError};
+use crate::grid::Grid;
+use crate::solution::Solution;
+
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 enum Space {
     Empty,
@@ -133,7 +140,7 @@ impl CubeConnection {
 #[derive(Debug)]
 struct CubeFaceInProgress {
     top_left: (usize, usize),
-    map: Vec<Vec<Space>>,
+    map: Grid<Space>,
     up: Option<CubeConnection>,
     left: Option<CubeConnection>,
     down: Option<CubeConnection>,
@@ -141,7 +148,7 @@ struct CubeFaceInProgress {
 }
 
 impl CubeFaceInProgress {
-    fn new(top_left: (usize, usize), map: Vec<Vec<Space>>) -> Self {
+    fn new(top_left: (usize, usize), map: Grid<Space>) -> Self {
         Self {
             top_left,
             map,
@@ -197,42 +204,47 @@ impl CubeFaceInProgress {
     }
 }
 
+pub struct Input {
+    map: Grid<Space>,
+    instructions: Vec<Instruction>,
+}
+
 #[derive(Debug)]
 struct CubeFace {
     top_left: (usize, usize),
-    map: Vec<Vec<Space>>,
+    map: Grid<Space>,
     up: CubeConnection,
     left: CubeConnection,
     down: CubeConnection,
     right: CubeConnection,
 }
 
-fn solve(input: &str) -> usize {
-    let (map, instructions) = parse_input(input);
+fn solve(input: &Input) -> usize {
+    let Input { map, instructions } = input;
 
-    let rows = map.len() as i32;
-    let cols = map[0].len() as i32;
+    let rows = map.height() as i32;
+    let cols = map.width() as i32;
 
     let mut i = 0;
-    let mut j = map[i]
-        .iter()
+    let mut j = map
+        .row(i)
         .position(|&space| space != Space::Void)
         .expect("first line should have a non-void space");
     let mut dx = 1;
     let mut dy = 0;
-    for instruction in &instructions {
+    for instruction in instructions {
         match instruction {
             Instruction::Move(n) => {
                 for _ in 0..*n {
                     let mut new_i = (i as i32 + rows + dy) % rows;
                     let mut new_j = (j as i32 + cols + dx) % cols;
 
-                    while map[new_i as usize][new_j as usize] == Space::Void {
+                    while map[(new_i as usize, new_j as usize)] == Space::Void {
                         new_i = (new_i + rows + dy) % rows;
                         new_j = (new_j + cols + dx) % cols;
                     }
 
-                    if map[new_i as usize][new_j as usize] == Space::Wall {
+                    if map[(new_i as usize, new_j as usize)] == Space::Wall {
                         break;
                     }
 
@@ -256,12 +268,12 @@ fn solve(input: &str) -> usize {
     solution(i, j, dx, dy)
 }
 
-fn solve_part_2(input: &str) -> usize {
-    let (map, instructions) = parse_input(input);
+fn solve_part_2(input: &Input) -> usize {
+    let Input { map, instructions } = input;
 
-    let cube_faces = split_map_into_cube_faces(&map);
+    let cube_faces = split_map_into_cube_faces(map);
 
-    let side_len = cube_faces[0].map.len();
+    let side_len = cube_faces[0].map.height();
 
     let mut cube_index = 0;
     let mut i = 0;
@@ -269,7 +281,7 @@ fn solve_part_2(input: &str) -> usize {
     let mut dx = 1;
     let mut dy = 0;
 
-    for instruction in &instructions {
+    for instruction in instructions {
         match instruction {
             Instruction::Move(n) => {
                 for _ in 0..*n {
@@ -300,7 +312,7 @@ fn solve_part_2(input: &str) -> usize {
                     new_i = (((new_i + side_len) as i32 + new_dy) % side_len as i32) as usize;
                     new_j = (((new_j + side_len) as i32 + new_dx) % side_len as i32) as usize;
 
-                    if cube_faces[new_cube_index].map[new_i][new_j] == Space::Wall {
+                    if cube_faces[new_cube_index].map[(new_i, new_j)] == Space::Wall {
                         break;
                     }
 
@@ -342,19 +354,19 @@ fn solution(i: usize, j: usize, dx: i32, dy: i32) -> usize {
     1000 * (i + 1) + 4 * (j + 1) + facing_value
 }
 
-fn split_map_into_cube_faces(map: &Vec<Vec<Space>>) -> Vec<CubeFace> {
-    let num_non_void: usize = map
+fn split_map_into_cube_faces(map: &Grid<Space>) -> Vec<CubeFace> {
+    let num_non_void = map
         .iter()
-        .map(|row| row.iter().filter(|&&space| space != Space::Void).count())
-        .sum();
+        .filter(|(_, &space)| space != Space::Void)
+        .count();
 
     let cube_face_size = num_non_void / 6;
     let cube_side_len = (cube_face_size as f64).sqrt().round() as usize;
 
     let mut cube_top_lefts: Vec<(usize, usize)> = Vec::new();
-    for i in (0..map.len()).step_by(cube_side_len) {
-        for j in (0..map[i].len()).step_by(cube_side_len) {
-            if map[i][j] != Space::Void {
+    for i in (0..map.height()).step_by(cube_side_len) {
+        for j in (0..map.width()).step_by(cube_side_len) {
+            if map[(i, j)] != Space::Void {
                 cube_top_lefts.push((i, j));
             }
         }
@@ -362,12 +374,9 @@ fn split_map_into_cube_faces(map: &Vec<Vec<Space>>) -> Vec<CubeFace> {
 
     let mut cube_faces_in_progress: Vec<CubeFaceInProgress> = Vec::with_capacity(6);
     for &(tl_i, tl_j) in &cube_top_lefts {
-        let mut cube_map = vec![vec![Space::Void; cube_side_len]; cube_side_len];
-        for i in 0..cube_side_len {
-            for j in 0..cube_side_len {
-                cube_map[i][j] = map[tl_i + i][tl_j + j];
-            }
-        }
+        let cube_map = map
+            .subgrid((tl_i, tl_j), cube_side_len, cube_side_len)
+            .expect("cube faces should be inside the map");
         cube_faces_in_progress.push(CubeFaceInProgress::new((tl_i, tl_j), cube_map));
     }
 
@@ -454,55 +463,136 @@ fn split_map_into_cube_faces(map: &Vec<Vec<Space>>) -> Vec<CubeFace> {
         .collect()
 }
 
-fn parse_input(input: &str) -> (Vec<Vec<Space>>, Vec<Instruction>) {
+fn parse_input(input: &str) -> Result<Input, ParseError> {
     let lines: Vec<_> = input.lines().collect();
+    if lines.len() < 3 || !lines[lines.len() - 2].is_empty() {
+        return Err(ParseError::new(
+            lines.len().max(1),
+            1,
+            "input should be a map followed by an empty line and a line of instructions",
+        ));
+    }
 
     let map_lines = &lines[..lines.len() - 2];
-    let map = parse_map(map_lines);
+    let map = parse_map(map_lines)?;
+    check_cube_net(&map)?;
 
-    let instructions = parse_instructions(input.lines().last().expect("input should not be empty"));
+    let instructions = parse_instructions(lines.len(), lines[lines.len() - 1])?;
 
-    (map, instructions)
+    Ok(Input { map, instructions })
 }
 
-fn parse_map(map_lines: &[&str]) -> Vec<Vec<Space>> {
-    let rows = map_lines.len();
-    let cols = map_lines
+fn parse_map(map_lines: &[&str]) -> Result<Grid<Space>, ParseError> {
+    Grid::parse_padded(&map_lines.join("\n"), ' ', |c| match c {
+        ' ' => Some(Space::Void),
+        '.' => Some(Space::Empty),
+        '#' => Some(Space::Wall),
+        _ => None,
+    })
+}
+
+// Part 2 folds the map into a cube, so it has to be made of six n x n faces, lined up on a grid
+// of n x n blocks, that fold up without any two landing on the same side of the cube
+fn check_cube_net(map: &Grid<Space>) -> Result<(), ParseError> {
+    let num_non_void = map
         .iter()
-        .map(|line| line.len())
-        .max()
-        .expect("map should not be empty");
-
-    let mut map = vec![vec![Space::Void; cols]; rows];
-    for (i, line) in map_lines.iter().enumerate() {
-        for (j, c) in line.chars().enumerate() {
-            map[i][j] = match c {
-                ' ' => Space::Void,
-                '.' => Space::Empty,
-                '#' => Space::Wall,
-                _ => panic!("unexpected char: {c}"),
+        .filter(|(_, &space)| space != Space::Void)
+        .count();
+    let side_len = ((num_non_void / 6) as f64).sqrt().round() as usize;
+    if side_len == 0 || 6 * side_len * side_len != num_non_void {
+        return Err(ParseError::new(
+            1,
+            1,
+            format!("map should have 6 * n * n tiles to fold into a cube, had {num_non_void}"),
+        ));
+    }
+
+    let mut faces: Vec<(usize, usize)> = Vec::new();
+    for block_i in 0..map.height().div_ceil(side_len) {
+        for block_j in 0..map.width().div_ceil(side_len) {
+            let top_left = (block_i * side_len, block_j * side_len);
+            let is_face = map[top_left] != Space::Void;
+            for i in top_left.0..top_left.0 + side_len {
+                for j in top_left.1..top_left.1 + side_len {
+                    let in_face = map.get((i, j)).is_some_and(|&space| space != Space::Void);
+                    if in_face != is_face {
+                        return Err(ParseError::new(
+                            i.min(map.height() - 1) + 1,
+                            j + 1,
+                            format!("cube faces should be {side_len} x {side_len} blocks"),
+                        ));
+                    }
+                }
+            }
+            if is_face {
+                faces.push((block_i, block_j));
+            }
+        }
+    }
+
+    // Fold the faces up by keeping track of which way each one's normal, right and down point
+    type Vector = [i32; 3];
+    let neg = |[x, y, z]: Vector| [-x, -y, -z];
+    let mut orientations: HashMap<(usize, usize), (Vector, Vector, Vector)> = HashMap::new();
+    orientations.insert(faces[0], ([0, 0, 1], [1, 0, 0], [0, 1, 0]));
+    let mut stack = vec![faces[0]];
+    while let Some((i, j)) = stack.pop() {
+        let (normal, right, down) = orientations[&(i, j)];
+        let neighbours = [
+            (i.checked_sub(1).map(|i| (i, j)), (neg(down), right, normal)),
+            (Some((i + 1, j)), (down, right, neg(normal))),
+            (j.checked_sub(1).map(|j| (i, j)), (neg(right), normal, down)),
+            (Some((i, j + 1)), (right, neg(normal), down)),
+        ];
+        for (neighbour, orientation) in neighbours {
+            if let Some(neighbour) = neighbour.filter(|neighbour| faces.contains(neighbour)) {
+                if let Entry::Vacant(entry) = orientations.entry(neighbour) {
+                    entry.insert(orientation);
+                    stack.push(neighbour);
+                }
             }
         }
     }
 
-    map
+    if orientations.len() < 6 {
+        return Err(ParseError::new(1, 1, "cube faces should all be connected"));
+    }
+    let normals: HashSet<Vector> = orientations
+        .values()
+        .map(|&(normal, _, _)| normal)
+        .collect();
+    if normals.len() < 6 {
+        return Err(ParseError::new(
+            1,
+            1,
+            "cube faces should fold up without overlapping",
+        ));
+    }
+    Ok(())
 }
 
-fn parse_instructions(line: &str) -> Vec<Instruction> {
-    let mut chars = line.chars().peekable();
+fn parse_instructions(line_number: usize, line: &str) -> Result<Vec<Instruction>, ParseError> {
+    let mut chars = line.char_indices().peekable();
 
     let mut instructions: Vec<Instruction> = Vec::new();
-    while let Some(&c) = chars.peek() {
+    while let Some(&(start, c)) = chars.peek() {
         let instruction = match c {
             _c @ '0'..='9' => {
-                let mut s = String::new();
-                while let Some(&c) = chars.peek() {
-                    if !('0'..='9').contains(&c) {
+                let mut end = start;
+                while let Some(&(i, c)) = chars.peek() {
+                    if !c.is_ascii_digit() {
                         break;
                     }
-                    s.push(chars.next().unwrap());
+                    end = i + 1;
+                    chars.next();
                 }
-                Instruction::Move(s.parse().expect("move distance should be an integer"))
+                let distance = &line[start..end];
+                Instruction::Move(error::parse_number(
+                    line_number,
+                    line,
+                    distance,
+                    "move distance",
+                )?)
             }
             'R' => {
                 chars.next();
@@ -512,22 +602,41 @@ fn parse_instructions(line: &str) -> Vec<Instruction> {
                 chars.next();
                 Instruction::RotateLeft
             }
-            _ => panic!("unexpected char: {c}"),
+            _ => {
+                return Err(ParseError::at(
+                    line_number,
+                    line,
+                    &line[start..],
+                    format!("unexpected char: {c}"),
+                ))
+            }
         };
         instructions.push(instruction);
     }
 
-    instructions
+    Ok(instructions)
 }
 
-fn main() {
-    let input = advent_of_code_2022::read_input().expect("unable to read input file");
+pub struct Day22;
+
+impl Solution for Day22 {
+    const DAY: u32 = 22;
 
-    let solution1 = solve(&input);
-    println!("{solution1}");
+    type Input = Input;
+    type Output1 = usize;
+    type Output2 = usize;
 
-    let solution2 = solve_part_2(&input);
-    println!("{solution2}");
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Output1 {
+        solve(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Output2 {
+        solve_part_2(input)
+    }
 }
 
 #[cfg(test)]
@@ -538,11 +647,46 @@ mod tests {
 
     #[test]
     fn test_sample_input_part_1() {
-        assert_eq!(6032, solve(SAMPLE_INPUT));
+        assert_eq!(6032, solve(&parse_input(SAMPLE_INPUT).unwrap()));
     }
 
     #[test]
     fn test_sample_input_part_2() {
-        assert_eq!(5031, solve_part_2(SAMPLE_INPUT));
+        assert_eq!(5031, solve_part_2(&parse_input(SAMPLE_INPUT).unwrap()));
+    }
+
+    #[test]
+    fn test_cube_net() {
+        // The smallest possible cube, as a cross
+        assert!(parse_input(" .\n...\n .\n .\n\n1R1").is_ok());
+
+        assert_eq!(
+            Err(ParseError::new(
+                1,
+                1,
+                "map should have 6 * n * n tiles to fold into a cube, had 4"
+            )),
+            parse_input("..\n..\n\n1").map(|_| ())
+        );
+        assert_eq!(
+            Err(ParseError::new(
+                1,
+                1,
+                "cube faces should fold up without overlapping"
+            )),
+            parse_input("......\n\n1").map(|_| ())
+        );
+        assert_eq!(
+            Err(ParseError::new(1, 1, "cube faces should all be connected")),
+            parse_input("...\n\n...\n\n1").map(|_| ())
+        );
+
+        // One tile of the top face of the sample has moved down a row
+        let input = SAMPLE_INPUT.replacen("        ...#\n", "        ...\n", 1);
+        let input = input.replacen("...#.......#\n", "...#.......#.\n", 1);
+        assert_eq!(
+            Err(ParseError::new(1, 12, "cube faces should be 4 x 4 blocks")),
+            parse_input(&input).map(|_| ())
+        );
     }
 }
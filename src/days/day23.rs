@@ -0,0 +1,188 @@
+//! Day 23: Unstable Diffusion
+//!
+//! <https://adventofcode.com/2022/day/23>
+
+use std::cmp;
+use std::collections::{HashMap, HashSet};
+
+use crate::error::ParseError;
+use crate::grid::Grid;
+use crate::point::{Direction4, Direction8, Point2};
+use crate::solution::Solution;
+
+type Point = Point2<i32>;
+
+// The order that elves consider directions in, shifted by one each round
+const DIRECTION_ORDER: [Direction4; 4] = [
+    Direction4::North,
+    Direction4::South,
+    Direction4::West,
+    Direction4::East,
+];
+
+fn get_directions_shifted(iteration: usize) -> Vec<Direction4> {
+    let (l, r) = DIRECTION_ORDER.split_at(iteration % 4);
+    Vec::from_iter(r.iter().chain(l.iter()).cloned())
+}
+
+fn solve(elf_positions: &HashSet<Point>) -> i32 {
+    let mut elf_positions = elf_positions.clone();
+
+    for iteration in 0..10 {
+        elf_positions = simulate_iteration(iteration, elf_positions);
+    }
+
+    let (min_x, min_y) = get_minimums(&elf_positions);
+    let (max_x, max_y) = get_maximums(&elf_positions);
+
+    (max_x - min_x + 1) * (max_y - min_y + 1) - elf_positions.len() as i32
+}
+
+fn solve_part_2(elf_positions: &HashSet<Point>) -> usize {
+    let mut elf_positions = elf_positions.clone();
+
+    for iteration in 0.. {
+        let prev_positions = elf_positions.clone();
+        elf_positions = simulate_iteration(iteration, elf_positions);
+        if prev_positions == elf_positions {
+            return iteration + 1;
+        }
+    }
+
+    panic!("no solution found");
+}
+
+fn simulate_iteration(iteration: usize, elf_positions: HashSet<Point>) -> HashSet<Point> {
+    let directions = get_directions_shifted(iteration);
+
+    let mut proposed_new_positions: HashMap<Point, Point> =
+        HashMap::with_capacity(elf_positions.len());
+
+    for &elf in &elf_positions {
+        if !elf.neighbours8().any(|p| elf_positions.contains(&p)) {
+            // No adjacent elves
+            proposed_new_positions.insert(elf, elf);
+            continue;
+        }
+
+        let mut proposed_new_position = elf;
+        for &direction in &directions {
+            // The direction itself and the two diagonals on either side of it
+            let direction = Direction8::from(direction);
+            let adjacent = [direction.rotate_left(), direction, direction.rotate_right()];
+            if !adjacent
+                .into_iter()
+                .any(|d| elf_positions.contains(&(elf + d.offset())))
+            {
+                proposed_new_position = elf + direction.offset();
+                break;
+            }
+        }
+
+        proposed_new_positions.insert(elf, proposed_new_position);
+    }
+
+    let mut proposed_position_counts: HashMap<Point, usize> = HashMap::new();
+    for &proposed_new_position in proposed_new_positions.values() {
+        if let Some(count) = proposed_position_counts.get_mut(&proposed_new_position) {
+            *count += 1;
+        } else {
+            proposed_position_counts.insert(proposed_new_position, 1);
+        }
+    }
+
+    for (&elf, proposed_new_position) in proposed_new_positions.iter_mut() {
+        if proposed_position_counts
+            .get(proposed_new_position)
+            .copied()
+            .unwrap()
+            > 1
+        {
+            *proposed_new_position = elf;
+        }
+    }
+
+    proposed_new_positions.into_values().collect()
+}
+
+fn get_minimums(positions: &HashSet<Point>) -> (i32, i32) {
+    positions
+        .iter()
+        .fold((i32::MAX, i32::MAX), |(min_x, min_y), point| {
+            (cmp::min(min_x, point.x), cmp::min(min_y, point.y))
+        })
+}
+
+fn get_maximums(positions: &HashSet<Point>) -> (i32, i32) {
+    positions
+        .iter()
+        .fold((i32::MIN, i32::MIN), |(max_x, max_y), point| {
+            (cmp::max(max_x, point.x), cmp::max(max_y, point.y))
+        })
+}
+
+fn parse_input(input: &str) -> Result<HashSet<Point>, ParseError> {
+    let grid = Grid::parse(input, |c| match c {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    })?;
+
+    let elf_positions: HashSet<_> = grid
+        .iter()
+        .filter(|(_, &is_elf)| is_elf)
+        .map(|((i, j), _)| Point::new(j as i32, i as i32))
+        .collect();
+    if elf_positions.is_empty() {
+        return Err(ParseError::new(1, 1, "there should be at least one elf"));
+    }
+
+    Ok(elf_positions)
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    const DAY: u32 = 23;
+
+    type Input = HashSet<Point>;
+    type Output1 = i32;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Output1 {
+        solve(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Output2 {
+        solve_part_2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE_INPUT: &str = include_str!("sample_input/sample23.txt");
+
+    #[test]
+    fn test_sample_input_part_1() {
+        assert_eq!(110, solve(&parse_input(SAMPLE_INPUT).unwrap()));
+    }
+
+    #[test]
+    fn test_sample_input_part_2() {
+        assert_eq!(20, solve_part_2(&parse_input(SAMPLE_INPUT).unwrap()));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            Err(ParseError::new(1, 1, "there should be at least one elf")),
+            parse_input("...\n...")
+        );
+    }
+}
@@ -0,0 +1,283 @@
+//! Day 24: Blizzard Basin
+//!
+//! <https://adventofcode.com/2022/day/24>
+
+use crate::error::{self, ParseError};
+use crate::grid::{Grid, Position};
+use crate::search;
+use crate::solution::Solution;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Direction {
+    Up,
+    Left,
+    Right,
+    Down,
+}
+
+impl Direction {
+    // Where a blizzard at position moves to, wrapping around inside the valley walls
+    fn move_once(self, (i, j): Position, rows: usize, cols: usize) -> Position {
+        match self {
+            Self::Up => (if i == 1 { rows - 2 } else { i - 1 }, j),
+            Self::Left => (i, if j == 1 { cols - 2 } else { j - 1 }),
+            Self::Right => (i, if j == cols - 2 { 1 } else { j + 1 }),
+            Self::Down => (if i == rows - 2 { 1 } else { i + 1 }, j),
+        }
+    }
+}
+
+// The time is only kept modulo the blizzards' period, so that there are only so many states to
+// search when the elf cannot get across
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+struct SearchState {
+    elf_i: usize,
+    elf_j: usize,
+    phase: usize,
+}
+
+fn solve(initial_grid: &Grid<Vec<Direction>>) -> Result<usize, String> {
+    let grids = blizzard_cycle(initial_grid);
+    let (entrance, exit) = openings(initial_grid);
+
+    find_shortest_distance(&grids, entrance, 0, exit)
+}
+
+fn solve_part_2(initial_grid: &Grid<Vec<Direction>>) -> Result<usize, String> {
+    let grids = blizzard_cycle(initial_grid);
+    let (entrance, exit) = openings(initial_grid);
+
+    let first_step = find_shortest_distance(&grids, entrance, 0, exit)?;
+    let second_step = find_shortest_distance(&grids, exit, first_step, entrance)?;
+    find_shortest_distance(&grids, entrance, second_step, exit)
+}
+
+// The positions just outside of the valley where the elf starts and where it is headed
+fn openings(grid: &Grid<Vec<Direction>>) -> (Position, Position) {
+    ((0, 1), (grid.height() - 1, grid.width() - 2))
+}
+
+// Every distinct arrangement of the blizzards, one per minute, after which they repeat
+fn blizzard_cycle(initial_grid: &Grid<Vec<Direction>>) -> Vec<Grid<Vec<Direction>>> {
+    let (rows, cols) = (initial_grid.height() - 2, initial_grid.width() - 2);
+    let period = rows / gcd(rows, cols) * cols;
+
+    let mut grids = vec![initial_grid.clone()];
+    while grids.len() < period {
+        grids.push(move_blizzards(grids.last().unwrap()));
+    }
+    grids
+}
+
+fn gcd(a: usize, b: usize) -> usize {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+// A* search over (position, time) states, returning the minute at which the elf, having set off
+// from start at start_time, reaches goal
+fn find_shortest_distance(
+    grids: &[Grid<Vec<Direction>>],
+    start: Position,
+    start_time: usize,
+    goal: Position,
+) -> Result<usize, String> {
+    let rows = grids[0].height();
+    let cols = grids[0].width();
+
+    let neighbours = |state: &SearchState| {
+        let SearchState {
+            elf_i,
+            elf_j,
+            phase,
+        } = *state;
+
+        let next_phase = (phase + 1) % grids.len();
+        let next_grid = &grids[next_phase];
+
+        let mut next_states = Vec::new();
+        for (dx, dy) in [(1, 0), (-1, 0), (0, 1), (0, -1), (0, 0)] {
+            let new_i = elf_i as i32 + dy;
+            let new_j = elf_j as i32 + dx;
+
+            if (new_i == 0 && new_j != 1) || new_i < 0 || new_j <= 0 {
+                continue;
+            }
+
+            let new_i = new_i as usize;
+            let new_j = new_j as usize;
+            if (new_i == rows - 1 && new_j != cols - 2) || new_i > rows - 1 || new_j >= cols - 1 {
+                continue;
+            }
+
+            if !next_grid[(new_i, new_j)].is_empty() {
+                continue;
+            }
+
+            let new_state = SearchState {
+                elf_i: new_i,
+                elf_j: new_j,
+                phase: next_phase,
+            };
+            next_states.push((new_state, 1));
+        }
+        next_states
+    };
+
+    // Lower bound for the remaining number of moves
+    let heuristic =
+        |state: &SearchState| state.elf_i.abs_diff(goal.0) + state.elf_j.abs_diff(goal.1);
+
+    let initial_state = SearchState {
+        elf_i: start.0,
+        elf_j: start.1,
+        phase: start_time % grids.len(),
+    };
+    let path = search::astar(initial_state, neighbours, heuristic, |state| {
+        (state.elf_i, state.elf_j) == goal
+    })
+    .ok_or_else(|| String::from("no way through the valley"))?;
+
+    Ok(start_time + path.cost)
+}
+
+fn move_blizzards(grid: &Grid<Vec<Direction>>) -> Grid<Vec<Direction>> {
+    let rows = grid.height();
+    let cols = grid.width();
+
+    let mut new_grid = Grid::new(cols, rows, Vec::new());
+    for (position, blizzards) in grid.iter() {
+        for &direction in blizzards {
+            new_grid[direction.move_once(position, rows, cols)].push(direction);
+        }
+    }
+
+    new_grid
+}
+
+fn parse_input(input: &str) -> Result<Grid<Vec<Direction>>, ParseError> {
+    let grid = Grid::parse(input, |c| match c {
+        '#' | '.' => Some(vec![]),
+        '^' => Some(vec![Direction::Up]),
+        '<' => Some(vec![Direction::Left]),
+        '>' => Some(vec![Direction::Right]),
+        'v' => Some(vec![Direction::Down]),
+        _ => None,
+    })?;
+
+    if grid.height() < 3 {
+        return Err(ParseError::new(1, 1, "valley should have at least 3 rows"));
+    }
+    if grid.width() < 3 {
+        return Err(ParseError::new(
+            1,
+            1,
+            "valley should have at least 3 columns",
+        ));
+    }
+
+    // Blizzards only move inside the walls, and the valley itself has no walls in it. The elf
+    // always enters next to the top left corner and leaves next to the bottom right one.
+    let (rows, cols) = (grid.height(), grid.width());
+    for (line_number, line) in error::numbered_lines(input) {
+        let i = line_number - 1;
+        for (j, c) in line.chars().enumerate() {
+            let is_wall = i == 0 || i == rows - 1 || j == 0 || j == cols - 1;
+            let is_opening = (i, j) == (0, 1) || (i, j) == (rows - 1, cols - 2);
+            let message = match c {
+                '#' if is_opening => "entrance and exit should be open",
+                '#' if !is_wall => "walls should only be around the edge of the valley",
+                '^' | '<' | '>' | 'v' if is_wall => "blizzards should be inside the valley walls",
+                _ => continue,
+            };
+            return Err(ParseError::new(line_number, j + 1, message));
+        }
+    }
+
+    Ok(grid)
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    const DAY: u32 = 24;
+
+    type Input = Grid<Vec<Direction>>;
+    type Output1 = String;
+    type Output2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Output1 {
+        solve(input).map_or_else(|err| err, |n| n.to_string())
+    }
+
+    fn part2(input: &Self::Input) -> Self::Output2 {
+        solve_part_2(input).map_or_else(|err| err, |n| n.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE_INPUT: &str = include_str!("sample_input/sample24.txt");
+
+    #[test]
+    fn test_sample_input_part_1() {
+        assert_eq!(Ok(18), solve(&parse_input(SAMPLE_INPUT).unwrap()));
+    }
+
+    #[test]
+    fn test_sample_input_part_2() {
+        assert_eq!(Ok(54), solve_part_2(&parse_input(SAMPLE_INPUT).unwrap()));
+    }
+
+    #[test]
+    fn test_no_way_through() {
+        // The blizzard passes through the only square in the valley every minute
+        let grid = parse_input("#.#\n#v#\n#.#").unwrap();
+        assert_eq!(Err(String::from("no way through the valley")), solve(&grid));
+        assert_eq!(
+            Err(String::from("no way through the valley")),
+            solve_part_2(&grid)
+        );
+    }
+
+    #[test]
+    fn test_walls() {
+        assert_eq!(
+            Err(ParseError::new(
+                3,
+                4,
+                "walls should only be around the edge of the valley"
+            )),
+            parse_input(&SAMPLE_INPUT.replace("#.<..<<#", "#.<#.<<#")).map(|_| ())
+        );
+        assert_eq!(
+            Err(ParseError::new(
+                1,
+                2,
+                "blizzards should be inside the valley walls"
+            )),
+            parse_input(&SAMPLE_INPUT.replace("#.######", "#^######")).map(|_| ())
+        );
+        assert_eq!(
+            Err(ParseError::new(6, 7, "entrance and exit should be open")),
+            parse_input(&SAMPLE_INPUT.replace("######.#", "########")).map(|_| ())
+        );
+        assert_eq!(
+            Err(ParseError::new(
+                1,
+                1,
+                "valley should have at least 3 columns"
+            )),
+            parse_input("#\n.\n#").map(|_| ())
+        );
+    }
+}
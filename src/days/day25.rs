@@ -0,0 +1,144 @@
+//! Day 25: Full of Hot Air
+//!
+//! <https://adventofcode.com/2022/day/25>
+
+use crate::error::{self, ParseError};
+use crate::solution::Solution;
+
+fn solve(numbers: &[i64]) -> String {
+    numbers
+        .iter()
+        .try_fold(0_i64, |sum, &n| sum.checked_add(n))
+        .map_or_else(
+            || String::from("sum of the numbers overflows"),
+            to_snafu_number,
+        )
+}
+
+fn parse_input(input: &str) -> Result<Vec<i64>, ParseError> {
+    error::numbered_lines(input)
+        .map(|(line_number, line)| parse_snafu_number(line_number, line))
+        .collect()
+}
+
+// Digits can be negative, so the running total may leave the range of an i64 on its way to a
+// value that fits
+fn parse_snafu_number(line_number: usize, line: &str) -> Result<i64, ParseError> {
+    let too_large = || ParseError::new(line_number, 1, "SNAFU number is too large");
+
+    let n = line.chars().enumerate().try_fold(0_i128, |n, (j, c)| {
+        let digit: i128 = match c {
+            '=' => -2,
+            '-' => -1,
+            '0'..='2' => (c as i128) - ('0' as i128),
+            _ => {
+                return Err(ParseError::new(
+                    line_number,
+                    j + 1,
+                    "SNAFU digits should be =, -, 0, 1, or 2",
+                ))
+            }
+        };
+        n.checked_mul(5)
+            .and_then(|n| n.checked_add(digit))
+            .ok_or_else(too_large)
+    })?;
+
+    i64::try_from(n).map_err(|_| too_large())
+}
+
+fn to_snafu_number(mut n: i64) -> String {
+    if n == 0 {
+        return String::from("0");
+    }
+
+    let mut s = String::new();
+    while n != 0 {
+        // Digits 3 and 4 become -2 and -1 with a carry into the next place
+        match n.rem_euclid(5) {
+            3 => s.push('='),
+            4 => s.push('-'),
+            rem => s.push((rem as u8 + b'0') as char),
+        }
+        n = n.div_euclid(5) + i64::from(n.rem_euclid(5) > 2);
+    }
+
+    s.chars().rev().collect()
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    const DAY: u32 = 25;
+
+    const HAS_PART_2: bool = false;
+
+    type Input = Vec<i64>;
+    type Output1 = String;
+    type Output2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Output1 {
+        solve(input)
+    }
+
+    fn part2(_input: &Self::Input) -> Self::Output2 {
+        unreachable!("day 25 has no part 2")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE_INPUT: &str = include_str!("sample_input/sample25.txt");
+
+    #[test]
+    fn test_sample_input() {
+        assert_eq!(
+            String::from("2=-1=0"),
+            solve(&parse_input(SAMPLE_INPUT).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_snafu_numbers() {
+        for (n, snafu) in [
+            (0, "0"),
+            (3, "1="),
+            (2022, "1=11-2"),
+            (-1, "-"),
+            (-2022, "-2--1="),
+        ] {
+            assert_eq!(snafu, to_snafu_number(n));
+            assert_eq!(Ok(n), parse_snafu_number(1, snafu));
+        }
+
+        for n in [i64::MAX, i64::MIN] {
+            assert_eq!(Ok(n), parse_snafu_number(1, &to_snafu_number(n)));
+        }
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            Err(ParseError::new(
+                2,
+                3,
+                "SNAFU digits should be =, -, 0, 1, or 2"
+            )),
+            parse_input("1=\n1-3")
+        );
+        assert_eq!(
+            Err(ParseError::new(1, 1, "SNAFU number is too large")),
+            parse_input(&"1".repeat(31))
+        );
+        assert_eq!(
+            String::from("sum of the numbers overflows"),
+            solve(&[i64::MAX, 1])
+        );
+    }
+}
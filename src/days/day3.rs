@@ -0,0 +1,167 @@
+//! Day 3: Rucksack Reorganization
+//!
+//! <https://adventofcode.com/2022/day/3>
+
+use std::collections::HashSet;
+
+use crate::error::{self, ParseError};
+use crate::solution::Solution;
+
+// The item in both halves of the rucksack
+fn shared_item(line: &str) -> Option<char> {
+    let (lhalf, rhalf) = line.split_at(line.len() / 2);
+    let lchars: HashSet<_> = lhalf.chars().collect();
+    rhalf.chars().find(|c| lchars.contains(c))
+}
+
+// The item in all three rucksacks of a group
+fn badge(a: &str, b: &str, c: &str) -> Option<char> {
+    let achars: HashSet<_> = a.chars().collect();
+    let bchars: HashSet<_> = b.chars().collect();
+    c.chars()
+        .find(|ch| achars.contains(ch) && bchars.contains(ch))
+}
+
+fn solve(lines: &[String]) -> u32 {
+    lines
+        .iter()
+        .map(|line| priority(shared_item(line).expect("shared items are checked while parsing")))
+        .sum()
+}
+
+fn solve_part_2(lines: &[String]) -> u32 {
+    lines
+        .chunks_exact(3)
+        .map(|chunk| {
+            priority(
+                badge(&chunk[0], &chunk[1], &chunk[2]).expect("badges are checked while parsing"),
+            )
+        })
+        .sum()
+}
+
+fn priority(c: char) -> u32 {
+    if c.is_lowercase() {
+        (c as u32) - ('a' as u32) + 1
+    } else {
+        (c as u32) - ('A' as u32) + 27
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<String>, ParseError> {
+    let lines = error::numbered_lines(input)
+        .map(|(line_number, line)| {
+            if let Some(j) = line.find(|c: char| !c.is_ascii_alphabetic()) {
+                return Err(ParseError::new(
+                    line_number,
+                    j + 1,
+                    "items should be ASCII letters",
+                ));
+            }
+
+            if line.len() % 2 != 0 {
+                return Err(ParseError::new(
+                    line_number,
+                    1,
+                    "rucksack should have an even number of items",
+                ));
+            }
+
+            if shared_item(line).is_none() {
+                return Err(ParseError::new(
+                    line_number,
+                    1,
+                    "both halves of the rucksack should have an item in common",
+                ));
+            }
+
+            Ok(String::from(line))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if lines.len() % 3 != 0 {
+        return Err(ParseError::new(
+            lines.len().max(1),
+            1,
+            "the number of rucksacks should be a multiple of 3",
+        ));
+    }
+    for (group, chunk) in lines.chunks_exact(3).enumerate() {
+        if badge(&chunk[0], &chunk[1], &chunk[2]).is_none() {
+            return Err(ParseError::new(
+                group * 3 + 1,
+                1,
+                "each group of 3 rucksacks should have an item in common",
+            ));
+        }
+    }
+
+    Ok(lines)
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u32 = 3;
+
+    type Input = Vec<String>;
+    type Output1 = u32;
+    type Output2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Output1 {
+        solve(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Output2 {
+        solve_part_2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE_INPUT: &str = include_str!("sample_input/sample3.txt");
+
+    #[test]
+    fn test_sample_input_part_1() {
+        assert_eq!(157, solve(&parse_input(SAMPLE_INPUT).unwrap()));
+    }
+
+    #[test]
+    fn test_sample_input_part_2() {
+        assert_eq!(70, solve_part_2(&parse_input(SAMPLE_INPUT).unwrap()));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            Err(ParseError::new(
+                2,
+                1,
+                "both halves of the rucksack should have an item in common"
+            )),
+            parse_input("aa\nabcd")
+        );
+        assert_eq!(
+            Err(ParseError::new(
+                2,
+                1,
+                "the number of rucksacks should be a multiple of 3"
+            )),
+            parse_input("aa\nbb")
+        );
+        assert_eq!(
+            Err(ParseError::new(
+                4,
+                1,
+                "each group of 3 rucksacks should have an item in common"
+            )),
+            parse_input("aa\naa\naa\nbb\ncc\ndd")
+        );
+    }
+}
@@ -0,0 +1,114 @@
+//! Day 4: Camp Cleanup
+//!
+//! <https://adventofcode.com/2022/day/4>
+
+use crate::error::{self, ParseError};
+use crate::solution::Solution;
+
+pub struct Range {
+    left: u32,
+    right: u32,
+}
+
+impl Range {
+    // s is the range's slice of the input line, used for error locations
+    fn from_str(line_number: usize, line: &str, s: &str) -> Result<Range, ParseError> {
+        let (left, right) = s
+            .split_once('-')
+            .ok_or_else(|| ParseError::at(line_number, line, s, "range should have one dash"))?;
+
+        let left: u32 = error::parse_number(line_number, line, left, "left end")?;
+        let right: u32 = error::parse_number(line_number, line, right, "right end")?;
+
+        Ok(Range { left, right })
+    }
+
+    fn fully_contains(&self, other: &Range) -> bool {
+        self.left <= other.left && self.right >= other.right
+    }
+
+    fn overlaps(&self, other: &Range) -> bool {
+        !(self.right < other.left || self.left > other.right)
+    }
+}
+
+fn solve(pairs: &[(Range, Range)]) -> usize {
+    pairs
+        .iter()
+        .filter(|(left_range, right_range)| {
+            left_range.fully_contains(right_range) || right_range.fully_contains(left_range)
+        })
+        .count()
+}
+
+fn solve_part_2(pairs: &[(Range, Range)]) -> usize {
+    pairs
+        .iter()
+        .filter(|(left_range, right_range)| left_range.overlaps(right_range))
+        .count()
+}
+
+fn parse_input(input: &str) -> Result<Vec<(Range, Range)>, ParseError> {
+    error::numbered_lines(input)
+        .map(|(line_number, line)| parse_input_line(line_number, line))
+        .collect()
+}
+
+fn parse_input_line(line_number: usize, line: &str) -> Result<(Range, Range), ParseError> {
+    let (left_range, right_range) = line
+        .split_once(',')
+        .ok_or_else(|| ParseError::new(line_number, 1, "line should have one comma"))?;
+
+    Ok((
+        Range::from_str(line_number, line, left_range)?,
+        Range::from_str(line_number, line, right_range)?,
+    ))
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u32 = 4;
+
+    type Input = Vec<(Range, Range)>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Output1 {
+        solve(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Output2 {
+        solve_part_2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE_INPUT: &str = include_str!("sample_input/sample4.txt");
+
+    #[test]
+    fn test_sample_input_part_1() {
+        assert_eq!(2, solve(&parse_input(SAMPLE_INPUT).unwrap()));
+    }
+
+    #[test]
+    fn test_sample_input_part_2() {
+        assert_eq!(4, solve_part_2(&parse_input(SAMPLE_INPUT).unwrap()));
+    }
+
+    #[test]
+    fn test_invalid_range() {
+        let err = parse_input("2-4,6-8\n2-3,4-x\n").err().unwrap();
+        assert_eq!(
+            ParseError::new(2, 7, "right end should be an integer, was 'x'"),
+            err
+        );
+    }
+}
@@ -0,0 +1,484 @@
+//! Day 5: Supply Stacks
+//!
+//! <https://adventofcode.com/2022/day/5>
+
+use std::fmt::{self, Display};
+
+use crate::error::{self, ParseError};
+use crate::solution::{Options, Solution};
+
+#[derive(Debug)]
+struct Move {
+    from: usize,
+    to: usize,
+    num: usize,
+}
+
+// How a crane moves a group of crates from one stack to another
+pub trait CraneModel {
+    // Given the crates lifted off the top of a stack (bottom first), returns the order they end up
+    // in on the destination stack (bottom first)
+    fn restack(&self, lifted: &[char]) -> Vec<char>;
+}
+
+// The CrateMover 9000, which moves one crate at a time
+pub struct SingleCrane;
+
+impl CraneModel for SingleCrane {
+    fn restack(&self, lifted: &[char]) -> Vec<char> {
+        lifted.iter().rev().copied().collect()
+    }
+}
+
+// The CrateMover 9001, which moves any number of crates at once
+pub struct BulkCrane;
+
+impl CraneModel for BulkCrane {
+    fn restack(&self, lifted: &[char]) -> Vec<char> {
+        lifted.to_vec()
+    }
+}
+
+// Moves up to capacity crates at a time, keeping the order within each load
+pub struct CapacityCrane {
+    capacity: usize,
+}
+
+impl CapacityCrane {
+    pub fn new(capacity: usize) -> Self {
+        assert!(capacity > 0, "crane capacity should be positive");
+        Self { capacity }
+    }
+}
+
+impl CraneModel for CapacityCrane {
+    fn restack(&self, lifted: &[char]) -> Vec<char> {
+        // Each load is taken from the top of what remains on the source stack
+        lifted.rchunks(self.capacity).flatten().copied().collect()
+    }
+}
+
+// Accepts single (or 9000), bulk (or 9001), or capacity:<n>
+pub fn parse_crane(s: &str) -> Result<Box<dyn CraneModel>, String> {
+    match s {
+        "single" | "9000" => Ok(Box::new(SingleCrane)),
+        "bulk" | "9001" => Ok(Box::new(BulkCrane)),
+        _ => {
+            let capacity = s
+                .strip_prefix("capacity:")
+                .and_then(|capacity| capacity.parse().ok())
+                .filter(|&capacity| capacity > 0)
+                .ok_or_else(|| {
+                    format!("crane should be single, bulk, or capacity:<n> with n > 0, was {s}")
+                })?;
+            Ok(Box::new(CapacityCrane::new(capacity)))
+        }
+    }
+}
+
+pub struct Input {
+    stacks: Vec<Vec<char>>,
+    moves: Vec<Move>,
+    // Line number of the first move, for reporting invalid moves
+    first_move_line: usize,
+    // Replaces the CrateMover 9001 in part 2 when set with the crane option
+    part_2_crane: Option<Box<dyn CraneModel>>,
+}
+
+// A move that tries to take more crates than its stack holds
+#[derive(Debug, PartialEq, Eq)]
+pub struct InvalidMove {
+    // 0-based index into the list of moves
+    pub index: usize,
+    pub stack: usize,
+    pub requested: usize,
+    pub available: usize,
+}
+
+impl Display for InvalidMove {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "move {} takes {} crates from stack {}, which only has {}",
+            self.index + 1,
+            self.requested,
+            self.stack,
+            self.available
+        )
+    }
+}
+
+// Every state of the stacks, starting with the initial drawing and then one per move
+pub struct Simulation {
+    states: Vec<Vec<Vec<char>>>,
+}
+
+impl Simulation {
+    pub fn states(&self) -> &[Vec<Vec<char>>] {
+        &self.states
+    }
+
+    pub fn final_state(&self) -> &[Vec<char>] {
+        self.states
+            .last()
+            .expect("simulation should have an initial state")
+    }
+
+    // The crate on top of each stack at the end, with a space for each empty stack so that the
+    // rest still line up with their stack numbers
+    pub fn tops(&self) -> String {
+        self.final_state()
+            .iter()
+            .map(|stack| stack.last().copied().unwrap_or(' '))
+            .collect()
+    }
+}
+
+fn apply_move(
+    stacks: &mut [Vec<char>],
+    mov: &Move,
+    index: usize,
+    crane: &dyn CraneModel,
+) -> Result<(), InvalidMove> {
+    let from_stack = &mut stacks[mov.from - 1];
+    let truncated_len = from_stack.len().checked_sub(mov.num).ok_or(InvalidMove {
+        index,
+        stack: mov.from,
+        requested: mov.num,
+        available: from_stack.len(),
+    })?;
+
+    let moved_chars = crane.restack(&from_stack[truncated_len..]);
+
+    from_stack.truncate(truncated_len);
+
+    stacks[mov.to - 1].extend(&moved_chars);
+
+    Ok(())
+}
+
+pub fn simulate(input: &Input, crane: &dyn CraneModel) -> Result<Simulation, InvalidMove> {
+    let mut states = vec![input.stacks.clone()];
+
+    for (index, mov) in input.moves.iter().enumerate() {
+        let mut stacks = states.last().unwrap().clone();
+        apply_move(&mut stacks, mov, index, crane)?;
+        states.push(stacks);
+    }
+
+    Ok(Simulation { states })
+}
+
+// Check every move against the crate counts, which are the same whichever crane is used
+fn validate_moves(input: &Input) -> Result<(), ParseError> {
+    let mut counts: Vec<_> = input.stacks.iter().map(Vec::len).collect();
+
+    for (index, mov) in input.moves.iter().enumerate() {
+        let available = counts[mov.from - 1];
+        if available < mov.num {
+            let invalid_move = InvalidMove {
+                index,
+                stack: mov.from,
+                requested: mov.num,
+                available,
+            };
+            return Err(ParseError::new(
+                input.first_move_line + index,
+                1,
+                invalid_move.to_string(),
+            ));
+        }
+
+        counts[mov.from - 1] -= mov.num;
+        counts[mov.to - 1] += mov.num;
+    }
+
+    Ok(())
+}
+
+// Draw the stacks in the same format as the input, with the stack numbers underneath
+pub fn render_stacks(stacks: &[Vec<char>]) -> String {
+    let height = stacks.iter().map(Vec::len).max().unwrap_or(0);
+
+    let mut lines: Vec<String> = (0..height)
+        .rev()
+        .map(|level| {
+            let crates: Vec<_> = stacks
+                .iter()
+                .map(|stack| match stack.get(level) {
+                    Some(c) => format!("[{c}]"),
+                    None => String::from("   "),
+                })
+                .collect();
+            crates.join(" ")
+        })
+        .collect();
+
+    let numbers: Vec<_> = (1..=stacks.len()).map(|i| format!(" {i} ")).collect();
+    lines.push(numbers.join(" "));
+
+    lines.join("\n")
+}
+
+fn solve(input: &Input, crane: &dyn CraneModel) -> String {
+    simulate(input, crane)
+        .expect("moves should have been validated while parsing")
+        .tops()
+}
+
+fn parse_input(input: &str) -> Result<Input, ParseError> {
+    let mut lines = input.lines();
+
+    let stacks_lines: Vec<_> = lines.by_ref().take_while(|line| !line.is_empty()).collect();
+    let moves_lines: Vec<_> = lines.collect();
+
+    let stacks = parse_stacks(&stacks_lines)?;
+
+    // Moves start after the stack drawing and the empty line following it
+    let first_move_line = stacks_lines.len() + 2;
+    let moves = parse_moves(&moves_lines, first_move_line, stacks.len())?;
+
+    let input = Input {
+        stacks,
+        moves,
+        first_move_line,
+        part_2_crane: None,
+    };
+    validate_moves(&input)?;
+
+    Ok(input)
+}
+
+fn parse_stacks(lines: &[&str]) -> Result<Vec<Vec<char>>, ParseError> {
+    let num_stacks: usize = lines
+        .last()
+        .and_then(|line| line.split_whitespace().last())
+        .and_then(|last_char| last_char.parse().ok())
+        .ok_or_else(|| {
+            ParseError::new(
+                lines.len().max(1),
+                1,
+                "line before empty line should end in a number",
+            )
+        })?;
+
+    let mut stacks: Vec<Vec<char>> = vec![Vec::new(); num_stacks];
+
+    for (line_index, line) in lines[..lines.len() - 1].iter().enumerate().rev() {
+        for (i, c) in line.chars().skip(1).step_by(4).enumerate() {
+            if c != ' ' {
+                if i >= num_stacks {
+                    return Err(ParseError::new(
+                        line_index + 1,
+                        4 * i + 2,
+                        format!("crate is outside of the {num_stacks} numbered stacks"),
+                    ));
+                }
+
+                stacks[i].push(c);
+            }
+        }
+    }
+
+    Ok(stacks)
+}
+
+fn parse_moves(
+    lines: &[&str],
+    first_line_number: usize,
+    num_stacks: usize,
+) -> Result<Vec<Move>, ParseError> {
+    lines
+        .iter()
+        .zip(first_line_number..)
+        .map(|(&line, line_number)| {
+            let split: Vec<_> = line.split_whitespace().collect();
+            let [num, from, to] = match split.as_slice() {
+                ["move", num, "from", from, "to", to] => [*num, *from, *to],
+                _ => {
+                    return Err(ParseError::new(
+                        line_number,
+                        1,
+                        "move should be in the format 'move <n> from <stack> to <stack>'",
+                    ))
+                }
+            };
+
+            let num = error::parse_number(line_number, line, num, "number to move")?;
+            let from = parse_stack_number(line_number, line, from, num_stacks)?;
+            let to = parse_stack_number(line_number, line, to, num_stacks)?;
+
+            Ok(Move { from, to, num })
+        })
+        .collect()
+}
+
+fn parse_stack_number(
+    line_number: usize,
+    line: &str,
+    s: &str,
+    num_stacks: usize,
+) -> Result<usize, ParseError> {
+    let stack: usize = error::parse_number(line_number, line, s, "stack number")?;
+    if !(1..=num_stacks).contains(&stack) {
+        return Err(ParseError::at(
+            line_number,
+            line,
+            s,
+            format!("stack number should be between 1 and {num_stacks}, was {stack}"),
+        ));
+    }
+
+    Ok(stack)
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u32 = 5;
+
+    type Input = Input;
+    type Output1 = String;
+    type Output2 = String;
+
+    const OPTIONS: &'static [&'static str] = &["crane"];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn configure(input: &mut Self::Input, options: &Options) -> Result<(), String> {
+        if let Some(crane) = options.get("crane") {
+            input.part_2_crane = Some(parse_crane(crane)?);
+        }
+        Ok(())
+    }
+
+    fn part1(input: &Self::Input) -> Self::Output1 {
+        solve(input, &SingleCrane)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Output2 {
+        solve(input, input.part_2_crane.as_deref().unwrap_or(&BulkCrane))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::{Part, Solver};
+
+    const SAMPLE_INPUT: &str = include_str!("sample_input/sample5.txt");
+
+    #[test]
+    fn test_sample_input_part_1() {
+        assert_eq!(
+            String::from("CMZ"),
+            solve(&parse_input(SAMPLE_INPUT).unwrap(), &SingleCrane)
+        );
+    }
+
+    #[test]
+    fn test_sample_input_part_2() {
+        assert_eq!(
+            String::from("MCD"),
+            solve(&parse_input(SAMPLE_INPUT).unwrap(), &BulkCrane)
+        );
+    }
+
+    #[test]
+    fn test_empty_stack() {
+        // Stack 1 ends up empty with either crane
+        let input = format!("{}move 1 from 1 to 2\n", SAMPLE_INPUT);
+        let result = Day5.solve(&input, &Part::ALL, &Options::new()).unwrap();
+        let answers: Vec<_> = result.parts.iter().map(|part| &part.answer).collect();
+        assert_eq!(vec![" CZ", " MD"], answers);
+    }
+
+    #[test]
+    fn test_invalid_move() {
+        let input = SAMPLE_INPUT.replace("move 1 from 1 to 2", "move 1 from 1 to 4");
+        assert_eq!(
+            Err(ParseError::new(
+                9,
+                18,
+                "stack number should be between 1 and 3, was 4"
+            )),
+            parse_input(&input).map(|_| ())
+        );
+
+        let input = SAMPLE_INPUT.replace("move 2 from 2 to 1", "move 3 from 2 to 1");
+        assert_eq!(
+            Err(ParseError::new(
+                8,
+                1,
+                "move 3 takes 3 crates from stack 2, which only has 2"
+            )),
+            parse_input(&input).map(|_| ())
+        );
+    }
+
+    #[test]
+    fn test_simulation() {
+        let input = parse_input(SAMPLE_INPUT).unwrap();
+        let simulation = simulate(&input, &SingleCrane).unwrap();
+
+        assert_eq!(5, simulation.states().len());
+        assert_eq!(
+            SAMPLE_INPUT.split("\n\n").next().unwrap(),
+            render_stacks(&simulation.states()[0])
+        );
+        assert_eq!(
+            "[D]        \n[N] [C]    \n[Z] [M] [P]\n 1   2   3 ",
+            render_stacks(&simulation.states()[1])
+        );
+        assert_eq!(
+            "        [Z]\n        [N]\n[M]     [D]\n[C]     [P]\n 1   2   3 ",
+            render_stacks(&simulation.states()[3])
+        );
+
+        let mut input = input;
+        input.moves.push(Move {
+            from: 2,
+            to: 1,
+            num: 5,
+        });
+        assert_eq!(
+            Some(InvalidMove {
+                index: 4,
+                stack: 2,
+                requested: 5,
+                available: 1,
+            }),
+            simulate(&input, &BulkCrane).err()
+        );
+    }
+
+    #[test]
+    fn test_crane_models() {
+        let lifted = ['a', 'b', 'c', 'd', 'e'];
+        assert_eq!(vec!['e', 'd', 'c', 'b', 'a'], SingleCrane.restack(&lifted));
+        assert_eq!(vec!['a', 'b', 'c', 'd', 'e'], BulkCrane.restack(&lifted));
+        assert_eq!(
+            vec!['c', 'd', 'e', 'a', 'b'],
+            CapacityCrane::new(3).restack(&lifted)
+        );
+        assert_eq!(
+            SingleCrane.restack(&lifted),
+            CapacityCrane::new(1).restack(&lifted)
+        );
+
+        let input = parse_input(SAMPLE_INPUT).unwrap();
+        assert_eq!("CMZ", solve(&input, &CapacityCrane::new(1)));
+        assert_eq!("MCD", solve(&input, &CapacityCrane::new(3)));
+        assert_eq!("MCZ", solve(&input, &CapacityCrane::new(2)));
+
+        let mut options = Options::new();
+        options.insert("crane", "capacity:2");
+        let result = Day5.solve(SAMPLE_INPUT, &[Part::Two], &options).unwrap();
+        assert_eq!("MCZ", result.parts[0].answer);
+
+        options.insert("crane", "capacity:0");
+        assert!(Day5.solve(SAMPLE_INPUT, &[Part::Two], &options).is_err());
+    }
+}
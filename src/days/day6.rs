@@ -0,0 +1,139 @@
+//! Day 6: Tuning Trouble
+//!
+//! <https://adventofcode.com/2022/day/6>
+
+use std::collections::HashSet;
+
+use crate::error::ParseError;
+use crate::solution::Solution;
+
+const PART_1_WINDOW_SIZE: usize = 4;
+const PART_2_WINDOW_SIZE: usize = 14;
+
+// How many characters have been read once the last window_size of them are all different
+fn find_marker(line: &str, window_size: usize) -> Option<usize> {
+    let chars: Vec<_> = line.chars().collect();
+    chars
+        .windows(window_size)
+        .enumerate()
+        .find_map(|(i, window)| {
+            let window_chars: HashSet<_> = window.iter().copied().collect();
+            if window_chars.len() == window_size {
+                Some(i + window_size)
+            } else {
+                None
+            }
+        })
+}
+
+fn solve(line: &str, window_size: usize) -> usize {
+    find_marker(line, window_size).expect("markers are checked while parsing")
+}
+
+fn parse_input(input: &str) -> Result<String, ParseError> {
+    let line = input
+        .lines()
+        .next()
+        .ok_or_else(|| ParseError::new(1, 1, "input should have a line"))?;
+
+    for window_size in [PART_1_WINDOW_SIZE, PART_2_WINDOW_SIZE] {
+        if find_marker(line, window_size).is_none() {
+            return Err(ParseError::new(
+                1,
+                1,
+                format!("datastream should have {window_size} different characters in a row"),
+            ));
+        }
+    }
+
+    Ok(String::from(line))
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u32 = 6;
+
+    type Input = String;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Output1 {
+        solve(input, PART_1_WINDOW_SIZE)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Output2 {
+        solve(input, PART_2_WINDOW_SIZE)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sample_inputs_part_1() {
+        assert_eq!(
+            7,
+            solve("mjqjpqmgbljsphdztnvjfqwrcgsmlb", PART_1_WINDOW_SIZE)
+        );
+        assert_eq!(5, solve("bvwbjplbgvbhsrlpgdmjqwftvncz", PART_1_WINDOW_SIZE));
+        assert_eq!(6, solve("nppdvjthqldpwncqszvftbrmjlhg", PART_1_WINDOW_SIZE));
+        assert_eq!(
+            10,
+            solve("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", PART_1_WINDOW_SIZE)
+        );
+        assert_eq!(
+            11,
+            solve("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", PART_1_WINDOW_SIZE)
+        );
+    }
+
+    #[test]
+    fn test_sample_inputs_part_2() {
+        assert_eq!(
+            19,
+            solve("mjqjpqmgbljsphdztnvjfqwrcgsmlb", PART_2_WINDOW_SIZE)
+        );
+        assert_eq!(
+            23,
+            solve("bvwbjplbgvbhsrlpgdmjqwftvncz", PART_2_WINDOW_SIZE)
+        );
+        assert_eq!(
+            23,
+            solve("nppdvjthqldpwncqszvftbrmjlhg", PART_2_WINDOW_SIZE)
+        );
+        assert_eq!(
+            29,
+            solve("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", PART_2_WINDOW_SIZE)
+        );
+        assert_eq!(
+            26,
+            solve("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", PART_2_WINDOW_SIZE)
+        );
+    }
+
+    #[test]
+    fn test_no_marker() {
+        assert_eq!(
+            Err(ParseError::new(
+                1,
+                1,
+                "datastream should have 4 different characters in a row"
+            )),
+            parse_input("abcabcabc")
+        );
+        assert_eq!(
+            Err(ParseError::new(
+                1,
+                1,
+                "datastream should have 14 different characters in a row"
+            )),
+            parse_input("abcdefghijklmabcdefghijklm")
+        );
+    }
+}
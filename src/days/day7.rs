@@ -0,0 +1,527 @@
+//! Day 7: No Space Left On Device
+//!
+//! <https://adventofcode.com/2022/day/7>
+
+use std::fmt::Write;
+use std::iter::Peekable;
+
+use crate::error::{self, ParseError};
+use crate::solution::Solution;
+
+// Index of a directory in the filesystem's arena
+pub type DirectoryId = usize;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct File {
+    pub name: String,
+    pub size: u32,
+}
+
+#[derive(Debug, Clone)]
+enum Entry {
+    Directory(DirectoryId),
+    File(File),
+}
+
+#[derive(Debug, Clone)]
+struct Directory {
+    name: String,
+    parent: Option<DirectoryId>,
+    // In the order that ls listed them
+    entries: Vec<Entry>,
+}
+
+// Directory tree stored as an arena, where a directory is always added after its parent
+#[derive(Debug, Clone)]
+pub struct Filesystem {
+    directories: Vec<Directory>,
+}
+
+impl Filesystem {
+    pub const ROOT: DirectoryId = 0;
+
+    pub fn new() -> Self {
+        Self {
+            directories: vec![Directory {
+                name: String::from("/"),
+                parent: None,
+                entries: Vec::new(),
+            }],
+        }
+    }
+
+    pub fn add_directory(
+        &mut self,
+        parent: DirectoryId,
+        name: &str,
+    ) -> Result<DirectoryId, String> {
+        if self.contains_name(parent, name) {
+            return Err(format!("{name} already exists in {}", self.path(parent)));
+        }
+
+        let id = self.directories.len();
+        self.directories.push(Directory {
+            name: String::from(name),
+            parent: Some(parent),
+            entries: Vec::new(),
+        });
+        self.directories[parent].entries.push(Entry::Directory(id));
+        Ok(id)
+    }
+
+    pub fn add_file(&mut self, parent: DirectoryId, name: &str, size: u32) -> Result<(), String> {
+        if self.contains_name(parent, name) {
+            return Err(format!("{name} already exists in {}", self.path(parent)));
+        }
+
+        let file = File {
+            name: String::from(name),
+            size,
+        };
+        self.directories[parent].entries.push(Entry::File(file));
+        Ok(())
+    }
+
+    fn contains_name(&self, directory: DirectoryId, name: &str) -> bool {
+        self.subdirectory(directory, name).is_some() || self.file(directory, name).is_some()
+    }
+
+    pub fn subdirectory(&self, directory: DirectoryId, name: &str) -> Option<DirectoryId> {
+        self.subdirectories(directory)
+            .find(|&id| self.directories[id].name == name)
+    }
+
+    pub fn file(&self, directory: DirectoryId, name: &str) -> Option<&File> {
+        self.files(directory).find(|file| file.name == name)
+    }
+
+    pub fn subdirectories(&self, directory: DirectoryId) -> impl Iterator<Item = DirectoryId> + '_ {
+        self.directories[directory]
+            .entries
+            .iter()
+            .filter_map(|entry| match entry {
+                Entry::Directory(id) => Some(*id),
+                Entry::File(_) => None,
+            })
+    }
+
+    pub fn files(&self, directory: DirectoryId) -> impl Iterator<Item = &File> + '_ {
+        self.directories[directory]
+            .entries
+            .iter()
+            .filter_map(|entry| match entry {
+                Entry::Directory(_) => None,
+                Entry::File(file) => Some(file),
+            })
+    }
+
+    pub fn parent(&self, directory: DirectoryId) -> Option<DirectoryId> {
+        self.directories[directory].parent
+    }
+
+    pub fn name(&self, directory: DirectoryId) -> &str {
+        &self.directories[directory].name
+    }
+
+    // Absolute path, e.g. / or /a/e
+    pub fn path(&self, directory: DirectoryId) -> String {
+        let mut names = Vec::new();
+        let mut current = directory;
+        while let Some(parent) = self.parent(current) {
+            names.push(self.name(current));
+            current = parent;
+        }
+
+        names.reverse();
+        format!("/{}", names.join("/"))
+    }
+
+    pub fn directories(&self) -> impl DoubleEndedIterator<Item = DirectoryId> {
+        0..self.directories.len()
+    }
+
+    // Total size of every directory including everything beneath it, indexed by DirectoryId
+    pub fn total_sizes(&self) -> Vec<u32> {
+        let mut sizes = vec![0; self.directories.len()];
+
+        // Children always come after their parent, so iterating backwards visits them first
+        for id in self.directories().rev() {
+            let files_size: u32 = self.files(id).map(|file| file.size).sum();
+            let directories_size: u32 = self.subdirectories(id).map(|child| sizes[child]).sum();
+            sizes[id] = files_size + directories_size;
+        }
+
+        sizes
+    }
+
+    pub fn total_size(&self, directory: DirectoryId) -> u32 {
+        self.total_sizes()[directory]
+    }
+
+    // Path and total size of every directory, each listed after everything beneath it like du
+    pub fn du(&self) -> Vec<(String, u32)> {
+        let sizes = self.total_sizes();
+        let mut listing = Vec::new();
+        self.du_inner(Self::ROOT, &sizes, &mut listing);
+        listing
+    }
+
+    fn du_inner(&self, directory: DirectoryId, sizes: &[u32], listing: &mut Vec<(String, u32)>) {
+        for child in self.subdirectories(directory) {
+            self.du_inner(child, sizes, listing);
+        }
+        listing.push((self.path(directory), sizes[directory]));
+    }
+
+    // Directories whose total size matches the predicate
+    pub fn find(&self, mut predicate: impl FnMut(u32) -> bool) -> Vec<DirectoryId> {
+        let sizes = self.total_sizes();
+        self.directories()
+            .filter(|&id| predicate(sizes[id]))
+            .collect()
+    }
+
+    // Render the tree in the same format as the puzzle text
+    pub fn tree(&self) -> String {
+        let mut output = String::new();
+        self.tree_inner(Self::ROOT, 0, &mut output);
+        output.truncate(output.trim_end().len());
+        output
+    }
+
+    fn tree_inner(&self, directory: DirectoryId, depth: usize, output: &mut String) {
+        let indent = "  ".repeat(depth);
+        writeln!(output, "{indent}- {} (dir)", self.name(directory)).unwrap();
+
+        for entry in &self.directories[directory].entries {
+            match entry {
+                Entry::Directory(id) => self.tree_inner(*id, depth + 1, output),
+                Entry::File(File { name, size }) => {
+                    writeln!(output, "{indent}  - {name} (file, size={size})").unwrap();
+                }
+            }
+        }
+    }
+}
+
+impl Default for Filesystem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+const PART_1_MAX_DIRECTORY_SIZE: u32 = 100000;
+
+const PART_2_DISK_SIZE: u32 = 70000000;
+const PART_2_TARGET_FREE_SPACE: u32 = 30000000;
+
+fn solve(filesystem: &Filesystem) -> u32 {
+    filesystem
+        .total_sizes()
+        .into_iter()
+        .filter(|&size| size <= PART_1_MAX_DIRECTORY_SIZE)
+        .sum()
+}
+
+// The smallest directory that frees up enough space when deleted
+fn find_directory_to_delete(filesystem: &Filesystem) -> DirectoryId {
+    let total_sizes = filesystem.total_sizes();
+
+    let root_dir_total_size = total_sizes[Filesystem::ROOT];
+
+    let target_space = PART_2_DISK_SIZE - PART_2_TARGET_FREE_SPACE;
+    filesystem
+        .find(|size| root_dir_total_size - size <= target_space)
+        .into_iter()
+        .min_by_key(|&id| total_sizes[id])
+        .unwrap()
+}
+
+fn solve_part_2(filesystem: &Filesystem) -> u32 {
+    filesystem.total_size(find_directory_to_delete(filesystem))
+}
+
+fn parse_input(input: &str) -> Result<Filesystem, ParseError> {
+    let mut filesystem = Filesystem::new();
+
+    let mut current_dir = Filesystem::ROOT;
+    let mut lines = error::numbered_lines(input).peekable();
+    while let Some((line_number, line)) = lines.next() {
+        if !line.starts_with('$') {
+            return Err(ParseError::new(
+                line_number,
+                1,
+                "expected a command starting with $",
+            ));
+        }
+
+        let mut split = line.split_whitespace().skip(1);
+        let command = split
+            .next()
+            .ok_or_else(|| ParseError::new(line_number, 1, "expecting cd or ls command after $"))?;
+        match command {
+            "cd" => {
+                let path = split.next().ok_or_else(|| {
+                    ParseError::at(
+                        line_number,
+                        line,
+                        command,
+                        "should be a directory name after cd command",
+                    )
+                })?;
+                current_dir = handle_cd_command(&filesystem, current_dir, path)
+                    .map_err(|message| ParseError::at(line_number, line, path, message))?;
+            }
+            "ls" => {
+                let ls_output = collect_ls_output(&mut lines);
+                handle_ls_command(&mut filesystem, current_dir, &ls_output)?;
+            }
+            _ => {
+                return Err(ParseError::at(
+                    line_number,
+                    line,
+                    command,
+                    format!("unknown command {command}; only cd and ls are supported"),
+                ))
+            }
+        }
+    }
+
+    Ok(filesystem)
+}
+
+// Follow a path that is either absolute or relative to the current directory, e.g. /, .., or a/b
+fn handle_cd_command(
+    filesystem: &Filesystem,
+    current_dir: DirectoryId,
+    path: &str,
+) -> Result<DirectoryId, String> {
+    let mut dir = if path.starts_with('/') {
+        Filesystem::ROOT
+    } else {
+        current_dir
+    };
+
+    for dir_name in path.split('/').filter(|dir_name| !dir_name.is_empty()) {
+        dir = match dir_name {
+            "." => dir,
+            ".." => filesystem
+                .parent(dir)
+                .ok_or_else(|| String::from("cannot cd .. from the root directory"))?,
+            _ => filesystem.subdirectory(dir, dir_name).ok_or_else(|| {
+                format!(
+                    "{} does not have a subdirectory named {dir_name}",
+                    filesystem.path(dir)
+                )
+            })?,
+        };
+    }
+
+    Ok(dir)
+}
+
+fn collect_ls_output<'a, I>(iter: &mut Peekable<I>) -> Vec<(usize, &'a str)>
+where
+    I: Iterator<Item = (usize, &'a str)>,
+{
+    let mut result: Vec<(usize, &str)> = Vec::new();
+
+    while let Some((_, line)) = iter.peek() {
+        if line.starts_with('$') {
+            break;
+        }
+
+        result.push(iter.next().unwrap());
+    }
+
+    result
+}
+
+fn handle_ls_command(
+    filesystem: &mut Filesystem,
+    current_dir: DirectoryId,
+    ls_output: &[(usize, &str)],
+) -> Result<(), ParseError> {
+    for &(line_number, line) in ls_output {
+        let (size, name) = line.split_once(' ').ok_or_else(|| {
+            ParseError::new(line_number, 1, "line in ls output should have one space")
+        })?;
+        // Listing the same directory again is fine as long as it agrees with the earlier listing
+        let added = if size == "dir" {
+            match filesystem.subdirectory(current_dir, name) {
+                Some(_) => Ok(()),
+                None => filesystem.add_directory(current_dir, name).map(|_| ()),
+            }
+        } else {
+            let size: u32 = error::parse_number(line_number, line, size, "size")?;
+            match filesystem.file(current_dir, name) {
+                Some(file) if file.size == size => Ok(()),
+                Some(file) => Err(format!(
+                    "{name} was previously listed with size {}",
+                    file.size
+                )),
+                None => filesystem.add_file(current_dir, name, size),
+            }
+        };
+        added.map_err(|message| ParseError::at(line_number, line, name, message))?;
+    }
+
+    Ok(())
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u32 = 7;
+
+    type Input = Filesystem;
+    type Output1 = u32;
+    type Output2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Output1 {
+        solve(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Output2 {
+        solve_part_2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE_INPUT: &str = include_str!("sample_input/sample7.txt");
+
+    #[test]
+    fn test_sample_input_part_1() {
+        assert_eq!(95437, solve(&parse_input(SAMPLE_INPUT).unwrap()));
+    }
+
+    #[test]
+    fn test_sample_input_part_2() {
+        assert_eq!(24933642, solve_part_2(&parse_input(SAMPLE_INPUT).unwrap()));
+    }
+
+    #[test]
+    fn test_filesystem_queries() {
+        let filesystem = parse_input(SAMPLE_INPUT).unwrap();
+
+        let to_delete = find_directory_to_delete(&filesystem);
+        assert_eq!("/d", filesystem.path(to_delete));
+
+        let e = filesystem
+            .subdirectory(filesystem.subdirectory(Filesystem::ROOT, "a").unwrap(), "e")
+            .unwrap();
+        assert_eq!("/a/e", filesystem.path(e));
+        assert_eq!(
+            vec![&File {
+                name: String::from("i"),
+                size: 584
+            }],
+            filesystem.files(e).collect::<Vec<_>>()
+        );
+
+        assert_eq!(
+            vec![
+                (String::from("/a/e"), 584),
+                (String::from("/a"), 94853),
+                (String::from("/d"), 24933642),
+                (String::from("/"), 48381165),
+            ],
+            filesystem.du()
+        );
+
+        let small: Vec<_> = filesystem
+            .find(|size| size <= 100000)
+            .into_iter()
+            .map(|id| filesystem.name(id))
+            .collect();
+        assert_eq!(vec!["a", "e"], small);
+    }
+
+    #[test]
+    fn test_extended_transcript() {
+        let transcript = "\
+$ ls
+dir a
+10 b
+$ cd a
+$ ls
+dir c
+$ cd /
+$ ls
+dir a
+10 b
+$ cd a/c
+$ ls
+20 d
+$ cd ../..
+$ cd /a/./c
+$ ls
+20 d";
+
+        let filesystem = parse_input(transcript).unwrap();
+        assert_eq!(
+            vec![
+                (String::from("/a/c"), 20),
+                (String::from("/a"), 20),
+                (String::from("/"), 30),
+            ],
+            filesystem.du()
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            Err(ParseError::new(
+                2,
+                3,
+                "unknown command rm; only cd and ls are supported"
+            )),
+            parse_input("$ cd /\n$ rm -rf a").map(|_| ())
+        );
+        assert_eq!(
+            Err(ParseError::new(
+                3,
+                6,
+                "/a does not have a subdirectory named b"
+            )),
+            parse_input("$ ls\ndir a\n$ cd a/b").map(|_| ())
+        );
+        assert_eq!(
+            Err(ParseError::new(
+                4,
+                4,
+                "b was previously listed with size 10"
+            )),
+            parse_input("$ ls\n10 b\n$ ls\n20 b").map(|_| ())
+        );
+    }
+
+    #[test]
+    fn test_tree() {
+        let expected = "\
+- / (dir)
+  - a (dir)
+    - e (dir)
+      - i (file, size=584)
+    - f (file, size=29116)
+    - g (file, size=2557)
+    - h.lst (file, size=62596)
+  - b.txt (file, size=14848514)
+  - c.dat (file, size=8504156)
+  - d (dir)
+    - j (file, size=4060174)
+    - d.log (file, size=8033020)
+    - d.ext (file, size=5626152)
+    - k (file, size=7214296)";
+
+        assert_eq!(expected, parse_input(SAMPLE_INPUT).unwrap().tree());
+    }
+}
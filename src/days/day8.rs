@@ -0,0 +1,141 @@
+//! Day 8: Treetop Tree House
+//!
+//! <https://adventofcode.com/2022/day/8>
+
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+use crate::error::ParseError;
+use crate::grid::{Grid, Position};
+use crate::solution::Solution;
+
+fn solve(grid: &Grid<u8>) -> usize {
+    let rows = grid.height();
+    let cols = grid.width();
+
+    let mut visible_tree_positions = Grid::new(cols, rows, false);
+
+    for i in 0..rows {
+        let row_right = find_visible_positions(grid.row(i).copied());
+        for j in row_right.iter().copied() {
+            visible_tree_positions[(i, j)] = true;
+        }
+
+        let row_left = find_visible_positions(grid.row(i).copied().rev());
+        for j in row_left.iter().copied() {
+            visible_tree_positions[(i, cols - j - 1)] = true;
+        }
+    }
+
+    for j in 0..cols {
+        let col_down = find_visible_positions(grid.col(j).copied());
+        for i in col_down.iter().copied() {
+            visible_tree_positions[(i, j)] = true;
+        }
+
+        let col_up = find_visible_positions(grid.col(j).copied().rev());
+        for i in col_up.iter().copied() {
+            visible_tree_positions[(rows - i - 1, j)] = true;
+        }
+    }
+
+    visible_tree_positions
+        .iter()
+        .filter(|(_, &visible)| visible)
+        .count()
+}
+
+fn solve_part_2(grid: &Grid<u8>) -> usize {
+    grid.positions()
+        .map(|position| compute_scenic_score(grid, position))
+        .max()
+        .unwrap()
+}
+
+fn parse_input(input: &str) -> Result<Grid<u8>, ParseError> {
+    Grid::parse(input, |c| c.to_digit(10).map(|height| height as u8))
+}
+
+fn find_visible_positions<I>(iter: I) -> Vec<usize>
+where
+    I: Iterator<Item = u8>,
+{
+    let mut heap: BinaryHeap<Reverse<(u8, usize)>> = BinaryHeap::new();
+
+    for (i, height) in iter.enumerate() {
+        while let Some(top) = heap.peek() {
+            let (top_height, _) = top.0;
+            if top_height > height {
+                break;
+            }
+
+            heap.pop();
+        }
+
+        heap.push(Reverse((height, i)));
+    }
+
+    heap.iter()
+        .map(|rev| {
+            let (_, i) = rev.0;
+            i
+        })
+        .collect()
+}
+
+fn compute_scenic_score(grid: &Grid<u8>, position: Position) -> usize {
+    let height = grid[position];
+
+    [(-1, 0), (1, 0), (0, -1), (0, 1)]
+        .into_iter()
+        .map(|direction| {
+            let mut viewing_distance = 0;
+            for (_, &tree) in grid.ray(position, direction) {
+                viewing_distance += 1;
+                if tree >= height {
+                    break;
+                }
+            }
+            viewing_distance
+        })
+        .product()
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u32 = 8;
+
+    type Input = Grid<u8>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Output1 {
+        solve(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Output2 {
+        solve_part_2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE_INPUT: &str = include_str!("sample_input/sample8.txt");
+
+    #[test]
+    fn test_sample_input_part_1() {
+        assert_eq!(21, solve(&parse_input(SAMPLE_INPUT).unwrap()));
+    }
+
+    #[test]
+    fn test_sample_input_part_2() {
+        assert_eq!(8, solve_part_2(&parse_input(SAMPLE_INPUT).unwrap()));
+    }
+}
@@ -0,0 +1,138 @@
+//! Day 9: Rope Bridge
+//!
+//! <https://adventofcode.com/2022/day/9>
+
+use std::collections::HashSet;
+
+use crate::error::{self, ParseError};
+use crate::point::{Direction4, Point2};
+use crate::solution::Solution;
+
+type Point = Point2<i32>;
+
+pub struct Motion {
+    direction: Direction4,
+    distance: i32,
+}
+
+const PART_1_ROPE_LEN: usize = 2;
+const PART_2_ROPE_LEN: usize = 10;
+
+fn solve(motions: &[Motion], rope_len: usize) -> usize {
+    let mut knots = vec![Point::new(0, 0); rope_len];
+
+    let mut tail_visited: HashSet<Point> = HashSet::new();
+    tail_visited.insert(Point::new(0, 0));
+
+    for &Motion {
+        direction,
+        distance,
+    } in motions
+    {
+        for _ in 0..distance {
+            knots[0] += direction.offset();
+
+            for i in 1..knots.len() {
+                if knots[i - 1].chebyshev_distance(knots[i]) > 1 {
+                    knots[i] = move_tail(knots[i - 1], knots[i]);
+                }
+            }
+
+            tail_visited.insert(*knots.last().unwrap());
+        }
+    }
+
+    tail_visited.len()
+}
+
+fn parse_input(input: &str) -> Result<Vec<Motion>, ParseError> {
+    error::numbered_lines(input)
+        .map(|(line_number, line)| {
+            let (direction, distance) = line
+                .split_once(' ')
+                .ok_or_else(|| ParseError::new(line_number, 1, "every line should have a space"))?;
+            let distance: i32 = error::parse_number(line_number, line, distance, "distance")?;
+
+            let direction = parse_direction(direction).ok_or_else(|| {
+                ParseError::new(
+                    line_number,
+                    1,
+                    format!("direction should be R/L/U/D, was '{direction}'"),
+                )
+            })?;
+
+            Ok(Motion {
+                direction,
+                distance,
+            })
+        })
+        .collect()
+}
+
+fn parse_direction(direction: &str) -> Option<Direction4> {
+    match direction {
+        "R" => Some(Direction4::East),
+        "L" => Some(Direction4::West),
+        "U" => Some(Direction4::North),
+        "D" => Some(Direction4::South),
+        _ => None,
+    }
+}
+
+fn move_tail(head: Point, tail: Point) -> Point {
+    tail + (head - tail).signum()
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u32 = 9;
+
+    type Input = Vec<Motion>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Output1 {
+        solve(input, PART_1_ROPE_LEN)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Output2 {
+        solve(input, PART_2_ROPE_LEN)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE_INPUT: &str = include_str!("sample_input/sample9.txt");
+    const LARGER_SAMPLE_INPUT: &str = include_str!("sample_input/sample9-larger.txt");
+
+    #[test]
+    fn test_sample_input_part_1() {
+        assert_eq!(
+            13,
+            solve(&parse_input(SAMPLE_INPUT).unwrap(), PART_1_ROPE_LEN)
+        );
+    }
+
+    #[test]
+    fn test_sample_input_part_2() {
+        assert_eq!(
+            1,
+            solve(&parse_input(SAMPLE_INPUT).unwrap(), PART_2_ROPE_LEN)
+        );
+    }
+
+    #[test]
+    fn test_larger_sample_input_part_2() {
+        assert_eq!(
+            36,
+            solve(&parse_input(LARGER_SAMPLE_INPUT).unwrap(), PART_2_ROPE_LEN)
+        );
+    }
+}
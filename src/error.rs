@@ -0,0 +1,157 @@
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+// A malformed input location; line and column are both 1-based
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ParseError {
+    pub day: Option<u32>,
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, message: impl Into<String>) -> Self {
+        Self {
+            day: None,
+            line,
+            column,
+            message: message.into(),
+        }
+    }
+
+    // Points the error at part, which must be a subslice of line
+    pub fn at(line_number: usize, line: &str, part: &str, message: impl Into<String>) -> Self {
+        Self::new(line_number, column_of(line, part), message)
+    }
+
+    pub fn for_day(self, day: u32) -> Self {
+        Self {
+            day: Some(day),
+            ..self
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(day) = self.day {
+            write!(f, "day {day}, ")?;
+        }
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+// Returns the 1-based column at which part starts, or column 1 if part is not a subslice of line
+pub fn column_of(line: &str, part: &str) -> usize {
+    let line_start = line.as_ptr() as usize;
+    let part_start = part.as_ptr() as usize;
+
+    if part_start < line_start || part_start > line_start + line.len() {
+        return 1;
+    }
+
+    line[..part_start - line_start].chars().count() + 1
+}
+
+// Iterate over input lines paired with their 1-based line numbers
+pub fn numbered_lines(input: &str) -> impl Iterator<Item = (usize, &str)> {
+    input.lines().enumerate().map(|(i, line)| (i + 1, line))
+}
+
+// Group numbered lines into the blocks between blank lines. A trailing blank line is allowed, but
+// empty input and several blank lines in a row are errors rather than empty blocks.
+pub fn numbered_blocks(input: &str) -> Result<Vec<Vec<(usize, &str)>>, ParseError> {
+    let mut blocks = Vec::new();
+    let mut block = Vec::new();
+    for (line_number, line) in numbered_lines(input) {
+        if !line.is_empty() {
+            block.push((line_number, line));
+        } else if block.is_empty() {
+            return Err(ParseError::new(line_number, 1, "unexpected blank line"));
+        } else {
+            blocks.push(std::mem::take(&mut block));
+        }
+    }
+
+    if !block.is_empty() {
+        blocks.push(block);
+    }
+    if blocks.is_empty() {
+        return Err(ParseError::new(1, 1, "input should not be empty"));
+    }
+    Ok(blocks)
+}
+
+// Parse part (a subslice of line) as a number, describing it as `what` on failure
+pub fn parse_number<T: FromStr>(
+    line_number: usize,
+    line: &str,
+    part: &str,
+    what: &str,
+) -> Result<T, ParseError> {
+    part.parse().map_err(|_| {
+        ParseError::at(
+            line_number,
+            line,
+            part,
+            format!("{what} should be an integer, was '{part}'"),
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_column_of() {
+        let line = "move 1 from 2 to 1";
+        assert_eq!(1, column_of(line, &line[..4]));
+        assert_eq!(6, column_of(line, &line[5..6]));
+        assert_eq!(19, column_of(line, &line[18..]));
+    }
+
+    #[test]
+    fn test_parse_number() {
+        let line = "x=12, y=abc";
+        assert_eq!(Ok(12), parse_number::<i32>(3, line, &line[2..4], "x"));
+        assert_eq!(
+            Err(ParseError::new(3, 9, "y should be an integer, was 'abc'")),
+            parse_number::<i32>(3, line, &line[8..], "y")
+        );
+    }
+
+    #[test]
+    fn test_numbered_blocks() {
+        assert_eq!(
+            Ok(vec![vec![(1, "a"), (2, "b")], vec![(4, "c")]]),
+            numbered_blocks("a\nb\n\nc\n\n")
+        );
+        assert_eq!(
+            Err(ParseError::new(3, 1, "unexpected blank line")),
+            numbered_blocks("a\n\n\nb")
+        );
+        assert_eq!(
+            Err(ParseError::new(1, 1, "input should not be empty")),
+            numbered_blocks("")
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        let err = ParseError::new(4, 2, "unexpected char: ?");
+        assert_eq!("line 4, column 2: unexpected char: ?", err.to_string());
+        assert_eq!(
+            "day 10, line 4, column 2: unexpected char: ?",
+            err.for_day(10).to_string()
+        );
+    }
+}
@@ -0,0 +1,355 @@
+//! A dense 2D grid indexed by (row, column), shared by the days whose input is a character map
+
+use std::fmt::{self, Display};
+use std::ops::{Index, IndexMut};
+
+use crate::error::{self, ParseError};
+
+pub type Position = (usize, usize);
+
+const NEIGHBOUR_OFFSETS_4: [(isize, isize); 4] = [(-1, 0), (0, -1), (0, 1), (1, 0)];
+
+const NEIGHBOUR_OFFSETS_8: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    // Returns None if the rows are not all the same length
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Self> {
+        let height = rows.len();
+        let width = rows.first().map_or(0, Vec::len);
+        if rows.iter().any(|row| row.len() != width) {
+            return None;
+        }
+
+        Some(Self {
+            width,
+            height,
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    // Parse a rectangular character map, where f returns None for characters that are not allowed
+    pub fn parse(input: &str, f: impl FnMut(char) -> Option<T>) -> Result<Self, ParseError> {
+        Self::parse_lines(input, None, f)
+    }
+
+    // Parse a character map whose shorter rows are padded on the right with pad
+    pub fn parse_padded(
+        input: &str,
+        pad: char,
+        f: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        Self::parse_lines(input, Some(pad), f)
+    }
+
+    fn parse_lines(
+        input: &str,
+        pad: Option<char>,
+        mut f: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let lines: Vec<_> = error::numbered_lines(input).collect();
+        let width = lines
+            .iter()
+            .map(|(_, line)| line.chars().count())
+            .max()
+            .unwrap_or(0);
+        if width == 0 {
+            return Err(ParseError::new(1, 1, "grid should not be empty"));
+        }
+
+        let mut cells = Vec::with_capacity(width * lines.len());
+        for &(line_number, line) in &lines {
+            let line_width = line.chars().count();
+            let padding = match pad {
+                Some(pad) => std::iter::repeat_n(pad, width - line_width),
+                None if line_width == width => std::iter::repeat_n(' ', 0),
+                None => {
+                    return Err(ParseError::new(
+                        line_number,
+                        1,
+                        format!("every row should have {width} columns"),
+                    ))
+                }
+            };
+
+            for (j, c) in line.chars().chain(padding).enumerate() {
+                let cell = f(c).ok_or_else(|| {
+                    ParseError::new(line_number, j + 1, format!("unexpected char: {c}"))
+                })?;
+                cells.push(cell);
+            }
+        }
+
+        Ok(Self {
+            width,
+            height: lines.len(),
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (row, col): Position) -> bool {
+        row < self.height && col < self.width
+    }
+
+    pub fn get(&self, position: Position) -> Option<&T> {
+        self.contains(position)
+            .then(|| &self.cells[position.0 * self.width + position.1])
+    }
+
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        if self.contains(position) {
+            Some(&mut self.cells[position.0 * self.width + position.1])
+        } else {
+            None
+        }
+    }
+
+    // Move from position by the given (row, col) offset, returning None if that leaves the grid
+    pub fn offset(&self, (row, col): Position, (dr, dc): (isize, isize)) -> Option<Position> {
+        let position = (row.checked_add_signed(dr)?, col.checked_add_signed(dc)?);
+        self.contains(position).then_some(position)
+    }
+
+    pub fn row(&self, row: usize) -> impl DoubleEndedIterator<Item = &T> + '_ {
+        self.cells[row * self.width..(row + 1) * self.width].iter()
+    }
+
+    pub fn col(&self, col: usize) -> impl DoubleEndedIterator<Item = &T> + '_ {
+        (0..self.height).map(move |row| &self[(row, col)])
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> + '_ {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    // Walk from position (exclusive) in a straight line until leaving the grid
+    pub fn ray(
+        &self,
+        position: Position,
+        direction: (isize, isize),
+    ) -> impl Iterator<Item = (Position, &T)> + '_ {
+        std::iter::successors(self.offset(position, direction), move |&position| {
+            self.offset(position, direction)
+        })
+        .map(|position| (position, &self[position]))
+    }
+
+    // Down and to the right, starting at position
+    pub fn diagonal(&self, position: Position) -> impl Iterator<Item = &T> + '_ {
+        self.get(position)
+            .into_iter()
+            .chain(self.ray(position, (1, 1)).map(|(_, value)| value))
+    }
+
+    // Down and to the left, starting at position
+    pub fn anti_diagonal(&self, position: Position) -> impl Iterator<Item = &T> + '_ {
+        self.get(position)
+            .into_iter()
+            .chain(self.ray(position, (1, -1)).map(|(_, value)| value))
+    }
+
+    // Orthogonally adjacent positions that are inside the grid
+    pub fn neighbours4(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        NEIGHBOUR_OFFSETS_4
+            .iter()
+            .filter_map(move |&offset| self.offset(position, offset))
+    }
+
+    // Orthogonally and diagonally adjacent positions that are inside the grid
+    pub fn neighbours8(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        NEIGHBOUR_OFFSETS_8
+            .iter()
+            .filter_map(move |&offset| self.offset(position, offset))
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |col| (row, col)))
+    }
+
+    // Every cell in row-major order along with its position
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> + '_ {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn position(&self, predicate: impl FnMut(&T) -> bool) -> Option<Position> {
+        self.cells
+            .iter()
+            .position(predicate)
+            .map(|i| (i / self.width, i % self.width))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    // Copy out the section width columns wide and height rows tall whose top left corner is at
+    // position, or None if it does not fit inside the grid
+    pub fn subgrid(&self, (row, col): Position, width: usize, height: usize) -> Option<Self>
+    where
+        T: Clone,
+    {
+        if row + height > self.height || col + width > self.width {
+            return None;
+        }
+
+        let cells = (row..row + height)
+            .flat_map(|i| self.cells[i * self.width + col..i * self.width + col + width].iter())
+            .cloned()
+            .collect();
+
+        Some(Self {
+            width,
+            height,
+            cells,
+        })
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &Self::Output {
+        self.get(position).unwrap_or_else(|| {
+            panic!(
+                "position {position:?} is outside of {}x{} grid",
+                self.width, self.height
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut Self::Output {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(position)
+            .unwrap_or_else(|| panic!("position {position:?} is outside of {width}x{height} grid"))
+    }
+}
+
+// Renders each cell with its Display impl, one line per row
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, row) in self.rows().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            for value in row {
+                write!(f, "{value}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digit_grid() -> Grid<u32> {
+        Grid::parse("123\n456\n789", |c| c.to_digit(10)).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = digit_grid();
+        assert_eq!((3, 3), (grid.width(), grid.height()));
+        assert_eq!(6, grid[(1, 2)]);
+        assert_eq!(None, grid.get((3, 0)));
+        assert_eq!("123\n456\n789", grid.to_string());
+
+        assert_eq!(
+            Err(ParseError::new(2, 2, "unexpected char: x")),
+            Grid::parse("12\n3x", |c| c.to_digit(10))
+        );
+        assert_eq!(
+            Err(ParseError::new(2, 1, "every row should have 3 columns")),
+            Grid::parse("123\n45", |c| c.to_digit(10))
+        );
+
+        let padded = Grid::parse_padded("  #\n.", ' ', Some).unwrap();
+        assert_eq!("  #\n.  ", padded.to_string());
+    }
+
+    #[test]
+    fn test_iterators() {
+        let grid = digit_grid();
+        assert_eq!(vec![4, 5, 6], grid.row(1).copied().collect::<Vec<_>>());
+        assert_eq!(
+            vec![9, 6, 3],
+            grid.col(2).rev().copied().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![1, 5, 9],
+            grid.diagonal((0, 0)).copied().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![3, 5, 7],
+            grid.anti_diagonal((0, 2)).copied().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![((1, 0), &4), ((0, 0), &1)],
+            grid.ray((2, 0), (-1, 0)).collect::<Vec<_>>()
+        );
+        assert_eq!(Some((2, 1)), grid.position(|&n| n == 8));
+    }
+
+    #[test]
+    fn test_subgrid() {
+        let grid = digit_grid();
+        assert_eq!("23\n56", grid.subgrid((0, 1), 2, 2).unwrap().to_string());
+        assert_eq!("456", grid.subgrid((1, 0), 3, 1).unwrap().to_string());
+        assert_eq!(None, grid.subgrid((0, 2), 2, 1));
+        assert_eq!(None, grid.subgrid((2, 0), 1, 2));
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = digit_grid();
+        assert_eq!(
+            vec![(0, 1), (1, 0)],
+            grid.neighbours4((0, 0)).collect::<Vec<_>>()
+        );
+        assert_eq!(4, grid.neighbours4((1, 1)).count());
+        assert_eq!(3, grid.neighbours8((2, 2)).count());
+        assert_eq!(8, grid.neighbours8((1, 1)).count());
+    }
+}
@@ -0,0 +1,123 @@
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::{env, fs};
+
+// Overrides the directory that inputN.txt files are read from
+pub const INPUT_DIR_ENV_VAR: &str = "AOC_INPUT_DIR";
+
+const DEFAULT_INPUT_DIR: &str = "input";
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum InputSource {
+    Stdin,
+    File(PathBuf),
+    // inputN.txt in the input directory
+    Day(u32),
+    Inline(String),
+}
+
+impl InputSource {
+    // Interpret a command line argument, where - means stdin
+    pub fn from_arg(arg: &str) -> Self {
+        if arg == "-" {
+            Self::Stdin
+        } else {
+            Self::File(PathBuf::from(arg))
+        }
+    }
+
+    pub fn describe(&self) -> String {
+        match self {
+            Self::Stdin => String::from("stdin"),
+            Self::File(path) => path.display().to_string(),
+            Self::Day(day) => day_input_path(*day).display().to_string(),
+            Self::Inline(_) => String::from("inline input"),
+        }
+    }
+}
+
+// Read the input from the given source, normalizing line endings and trailing whitespace
+pub fn read_input(source: &InputSource) -> io::Result<String> {
+    let raw = match source {
+        InputSource::Stdin => {
+            let mut buf = String::new();
+            io::stdin().read_to_string(&mut buf)?;
+            buf
+        }
+        InputSource::File(path) => fs::read_to_string(path)?,
+        InputSource::Day(day) => fs::read_to_string(day_input_path(*day))?,
+        InputSource::Inline(s) => s.clone(),
+    };
+
+    Ok(normalize(&raw))
+}
+
+pub fn input_dir() -> PathBuf {
+    env::var_os(INPUT_DIR_ENV_VAR)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(DEFAULT_INPUT_DIR))
+}
+
+pub fn day_input_path(day: u32) -> PathBuf {
+    day_input_path_in(&input_dir(), day)
+}
+
+fn day_input_path_in(dir: &Path, day: u32) -> PathBuf {
+    dir.join(format!("input{day}.txt"))
+}
+
+// Convert CRLF to LF, strip trailing whitespace from every line, and drop trailing blank lines.
+// Leading whitespace is preserved because some inputs (e.g. day 5 and day 22) depend on it.
+pub fn normalize(input: &str) -> String {
+    let lines: Vec<_> = input.lines().map(str::trim_end).collect();
+
+    let len = lines
+        .iter()
+        .rposition(|line| !line.is_empty())
+        .map_or(0, |i| i + 1);
+
+    let mut result = String::with_capacity(input.len());
+    for line in &lines[..len] {
+        result.push_str(line);
+        result.push('\n');
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(
+            "    [D]\n[N] [C]\n\nmove 1 from 2 to 1\n",
+            normalize("    [D]    \r\n[N] [C]    \r\n\r\nmove 1 from 2 to 1\r\n\r\n\r\n")
+        );
+        assert_eq!("", normalize("\n \n"));
+    }
+
+    #[test]
+    fn test_read_inline() {
+        let source = InputSource::Inline(String::from("1000\r\n2000  \r\n"));
+        assert_eq!("1000\n2000\n", read_input(&source).unwrap());
+    }
+
+    #[test]
+    fn test_from_arg() {
+        assert_eq!(InputSource::Stdin, InputSource::from_arg("-"));
+        assert_eq!(
+            InputSource::File(PathBuf::from("input/input5.txt")),
+            InputSource::from_arg("input/input5.txt")
+        );
+    }
+
+    #[test]
+    fn test_day_input_path() {
+        assert_eq!(
+            PathBuf::from("/tmp/aoc/input17.txt"),
+            day_input_path_in(Path::new("/tmp/aoc"), 17)
+        );
+    }
+}
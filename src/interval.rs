@@ -0,0 +1,215 @@
+//! Sets of integers stored as sorted, disjoint, inclusive intervals, for when the values covered
+//! are too many to store one by one.
+
+use crate::point::Coordinate;
+
+// Every integer from start to end, inclusive
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Interval<T> {
+    pub start: T,
+    pub end: T,
+}
+
+impl<T: Coordinate> Interval<T> {
+    pub fn new(start: T, end: T) -> Self {
+        assert!(start <= end, "interval start should not be after its end");
+        Self { start, end }
+    }
+
+    pub fn len(&self) -> T {
+        self.end - self.start + T::ONE
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.start <= value && value <= self.end
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct IntervalSet<T> {
+    // Sorted, with a gap of at least one value between neighbouring intervals
+    intervals: Vec<Interval<T>>,
+}
+
+impl<T: Coordinate> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self {
+            intervals: Vec::new(),
+        }
+    }
+
+    pub fn intervals(&self) -> &[Interval<T>] {
+        &self.intervals
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    // The number of values covered
+    pub fn len(&self) -> T {
+        self.intervals
+            .iter()
+            .fold(T::ZERO, |total, interval| total + interval.len())
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let i = self
+            .intervals
+            .partition_point(|interval| interval.end < value);
+        self.intervals
+            .get(i)
+            .is_some_and(|interval| interval.contains(value))
+    }
+
+    // Add start..=end, merging it with any intervals that it overlaps or touches
+    pub fn insert(&mut self, start: T, end: T) {
+        let mut merged = Interval::new(start, end);
+
+        let first = self
+            .intervals
+            .partition_point(|interval| interval.end + T::ONE < start);
+        let last = self
+            .intervals
+            .partition_point(|interval| interval.start <= end + T::ONE);
+        if first < last {
+            merged.start = merged.start.min(self.intervals[first].start);
+            merged.end = merged.end.max(self.intervals[last - 1].end);
+        }
+
+        self.intervals.splice(first..last, [merged]);
+    }
+
+    // Add every interval in other
+    pub fn merge(&mut self, other: &Self) {
+        for interval in &other.intervals {
+            self.insert(interval.start, interval.end);
+        }
+    }
+
+    // Remove start..=end, splitting any interval that it falls inside of
+    pub fn subtract(&mut self, start: T, end: T) {
+        let removed = Interval::new(start, end);
+
+        let first = self
+            .intervals
+            .partition_point(|interval| interval.end < start);
+        let last = self
+            .intervals
+            .partition_point(|interval| interval.start <= end);
+        if first == last {
+            return;
+        }
+
+        let mut remaining = Vec::with_capacity(2);
+        if self.intervals[first].start < removed.start {
+            remaining.push(Interval::new(
+                self.intervals[first].start,
+                removed.start - T::ONE,
+            ));
+        }
+        if self.intervals[last - 1].end > removed.end {
+            remaining.push(Interval::new(
+                removed.end + T::ONE,
+                self.intervals[last - 1].end,
+            ));
+        }
+
+        self.intervals.splice(first..last, remaining);
+    }
+
+    // The values in start..=end that are not covered
+    pub fn gaps(&self, start: T, end: T) -> Self {
+        let mut gaps = Self::new();
+        let mut next = start;
+        for interval in &self.intervals {
+            if interval.start > end {
+                break;
+            }
+            if interval.start > next {
+                gaps.intervals
+                    .push(Interval::new(next, interval.start - T::ONE));
+            }
+            next = next.max(interval.end + T::ONE);
+        }
+
+        if next <= end {
+            gaps.intervals.push(Interval::new(next, end));
+        }
+        gaps
+    }
+}
+
+impl<T: Coordinate> FromIterator<Interval<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Interval<T>>>(iter: I) -> Self {
+        let mut set = Self::new();
+        for interval in iter {
+            set.insert(interval.start, interval.end);
+        }
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(intervals: &[(i32, i32)]) -> IntervalSet<i32> {
+        intervals
+            .iter()
+            .map(|&(start, end)| Interval::new(start, end))
+            .collect()
+    }
+
+    fn pairs(set: &IntervalSet<i32>) -> Vec<(i32, i32)> {
+        set.intervals()
+            .iter()
+            .map(|interval| (interval.start, interval.end))
+            .collect()
+    }
+
+    #[test]
+    fn test_insert() {
+        let mut s = set(&[(10, 12), (1, 3), (20, 25)]);
+        assert_eq!(vec![(1, 3), (10, 12), (20, 25)], pairs(&s));
+        assert_eq!(12, s.len());
+
+        // Touching intervals are merged as well as overlapping ones
+        s.insert(4, 9);
+        assert_eq!(vec![(1, 12), (20, 25)], pairs(&s));
+
+        s.insert(-5, 30);
+        assert_eq!(vec![(-5, 30)], pairs(&s));
+        assert!(s.contains(-5) && s.contains(30) && !s.contains(31));
+
+        let mut other = set(&[(40, 41)]);
+        other.merge(&s);
+        assert_eq!(vec![(-5, 30), (40, 41)], pairs(&other));
+    }
+
+    #[test]
+    fn test_subtract() {
+        let mut s = set(&[(0, 10), (20, 30)]);
+        s.subtract(3, 5);
+        assert_eq!(vec![(0, 2), (6, 10), (20, 30)], pairs(&s));
+
+        s.subtract(8, 25);
+        assert_eq!(vec![(0, 2), (6, 7), (26, 30)], pairs(&s));
+
+        s.subtract(-100, 100);
+        assert!(s.is_empty());
+        assert_eq!(0, s.len());
+    }
+
+    #[test]
+    fn test_gaps() {
+        let s = set(&[(0, 10), (13, 15), (20, 30)]);
+        assert_eq!(vec![(11, 12), (16, 19)], pairs(&s.gaps(0, 30)));
+        assert_eq!(
+            vec![(-3, -1), (11, 12), (16, 19), (31, 32)],
+            pairs(&s.gaps(-3, 32))
+        );
+        assert_eq!(vec![(12, 12)], pairs(&s.gaps(12, 14)));
+        assert!(s.gaps(21, 29).is_empty());
+    }
+}
@@ -0,0 +1,115 @@
+//! Minimal hand-written JSON serialization for machine-readable runner output
+
+use std::fmt::Write;
+
+use crate::solution::DayResult;
+
+// Quote and escape s as a JSON string
+pub fn string(s: &str) -> String {
+    let mut result = String::with_capacity(s.len() + 2);
+    result.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            '\r' => result.push_str("\\r"),
+            '\t' => result.push_str("\\t"),
+            c if (c as u32) < 0x20 => {
+                write!(result, "\\u{:04x}", c as u32).unwrap();
+            }
+            c => result.push(c),
+        }
+    }
+    result.push('"');
+    result
+}
+
+// A single-line JSON object describing one day's run. Elapsed times are in nanoseconds.
+//
+// {"day":6,"parse_elapsed_ns":1200,"parts":[{"part":1,"answer":"7","elapsed_ns":5300}],"error":null}
+pub fn day_result(day: u32, result: &Result<DayResult, String>) -> String {
+    let mut json = format!("{{\"day\":{day},");
+
+    match result {
+        Ok(result) => {
+            let parts: Vec<_> = result
+                .parts
+                .iter()
+                .map(|part_result| {
+                    format!(
+                        "{{\"part\":{},\"answer\":{},\"elapsed_ns\":{}}}",
+                        part_result.part,
+                        string(&part_result.answer),
+                        part_result.elapsed.as_nanos()
+                    )
+                })
+                .collect();
+
+            write!(
+                json,
+                "\"parse_elapsed_ns\":{},\"parts\":[{}],\"error\":null",
+                result.parse_elapsed.as_nanos(),
+                parts.join(",")
+            )
+            .unwrap();
+        }
+        Err(err) => {
+            write!(
+                json,
+                "\"parse_elapsed_ns\":null,\"parts\":[],\"error\":{}",
+                string(err)
+            )
+            .unwrap();
+        }
+    }
+
+    json.push('}');
+    json
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::{Part, PartResult};
+    use std::time::Duration;
+
+    #[test]
+    fn test_string() {
+        assert_eq!("\"abc\"", string("abc"));
+        assert_eq!(
+            "\"#..#\\n\\\"quoted\\\" \\\\ \\u0001\"",
+            string("#..#\n\"quoted\" \\ \u{1}")
+        );
+    }
+
+    #[test]
+    fn test_day_result() {
+        let result = DayResult {
+            parse_elapsed: Duration::from_nanos(1200),
+            parts: vec![
+                PartResult {
+                    part: Part::One,
+                    answer: String::from("7"),
+                    elapsed: Duration::from_nanos(5300),
+                },
+                PartResult {
+                    part: Part::Two,
+                    answer: String::from("19"),
+                    elapsed: Duration::from_micros(10),
+                },
+            ],
+        };
+        assert_eq!(
+            "{\"day\":6,\"parse_elapsed_ns\":1200,\"parts\":[\
+             {\"part\":1,\"answer\":\"7\",\"elapsed_ns\":5300},\
+             {\"part\":2,\"answer\":\"19\",\"elapsed_ns\":10000}],\"error\":null}",
+            day_result(6, &Ok(result))
+        );
+
+        assert_eq!(
+            "{\"day\":7,\"parse_elapsed_ns\":null,\"parts\":[],\"error\":\"bad \\\"input\\\"\"}",
+            day_result(7, &Err(String::from("bad \"input\"")))
+        );
+    }
+}
@@ -0,0 +1,103 @@
+//! Reads text drawn in the 4x6 font that Advent of Code puzzles use for letter output, such as
+//! the CRT screen in day 10. Glyphs are 4 columns wide with a blank column between letters; `#`
+//! is a lit pixel and any other character is dark.
+
+const GLYPH_WIDTH: usize = 4;
+const GLYPH_HEIGHT: usize = 6;
+const GLYPH_SPACING: usize = 1;
+
+#[rustfmt::skip]
+const FONT: [(char, [&str; GLYPH_HEIGHT]); 17] = [
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', [".###", "..#.", "..#.", "..#.", "..#.", ".###"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+// Read the letters drawn on screen, or describe the first glyph that could not be recognized
+pub fn recognize(screen: &str) -> Result<String, String> {
+    let rows: Vec<_> = screen
+        .lines()
+        .map(str::as_bytes)
+        .filter(|row| !row.is_empty())
+        .collect();
+    if rows.len() != GLYPH_HEIGHT {
+        return Err(format!(
+            "screen should be {GLYPH_HEIGHT} rows tall, was {}",
+            rows.len()
+        ));
+    }
+
+    let width = rows.iter().map(|row| row.len()).max().unwrap();
+    let num_glyphs = width.div_ceil(GLYPH_WIDTH + GLYPH_SPACING);
+
+    (0..num_glyphs)
+        .map(|i| {
+            let col = i * (GLYPH_WIDTH + GLYPH_SPACING);
+            let bits = glyph_bits(&rows, col);
+            FONT.iter()
+                .find(|(_, glyph)| font_bits(glyph) == bits)
+                .map(|&(letter, _)| letter)
+                .ok_or_else(|| format!("unrecognized glyph at column {}", col + 1))
+        })
+        .collect()
+}
+
+fn glyph_bits(rows: &[&[u8]], col: usize) -> u32 {
+    let mut bits = 0;
+    for row in rows {
+        for j in col..col + GLYPH_WIDTH {
+            bits = (bits << 1) | u32::from(row.get(j) == Some(&b'#'));
+        }
+    }
+    bits
+}
+
+fn font_bits(glyph: &[&str; GLYPH_HEIGHT]) -> u32 {
+    let rows: Vec<_> = glyph.iter().map(|row| row.as_bytes()).collect();
+    glyph_bits(&rows, 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_recognize() {
+        let screen = "\
+###..#....####.####.#..#.#....###..###..
+#..#.#....#....#....#..#.#....#..#.#..#.
+#..#.#....###..###..#..#.#....#..#.###..
+###..#....#....#....#..#.#....###..#..#.
+#....#....#....#....#..#.#....#....#..#.
+#....####.####.#.....##..####.#....###..";
+        assert_eq!(Ok(String::from("PLEFULPB")), recognize(screen));
+
+        // Dark pixels rendered as spaces, with the trailing column trimmed
+        let screen = " ##  #  #\n#  # #  #\n#  # ####\n#### #  #\n#  # #  #\n#  # #  #";
+        assert_eq!(Ok(String::from("AH")), recognize(screen));
+    }
+
+    #[test]
+    fn test_unrecognized() {
+        let screen = "####.#...\n#....#...\n###..#...\n#....#...\n#....#...\n####.#...";
+        assert_eq!(
+            Err(String::from("unrecognized glyph at column 6")),
+            recognize(screen)
+        );
+        assert!(recognize("##\n##").is_err());
+    }
+}
@@ -0,0 +1,321 @@
+//! Generic graph searches over implicit graphs described by a neighbour function.
+//!
+//! Nodes can be any hashable value. BFS treats every edge as having cost 1, while Dijkstra and A*
+//! take neighbour functions that return (node, cost) pairs.
+
+use std::cmp::Reverse;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+// Edge costs for weighted searches; Default must be zero
+pub trait Cost: Copy + Ord + Add<Output = Self> + Default {}
+
+impl<T: Copy + Ord + Add<Output = T> + Default> Cost for T {}
+
+// The shortest path found to a goal, including both the start node and the goal node
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Path<N, C> {
+    pub cost: C,
+    pub nodes: Vec<N>,
+}
+
+impl<N, C> Path<N, C> {
+    pub fn start(&self) -> &N {
+        self.nodes.first().expect("path should never be empty")
+    }
+
+    pub fn goal(&self) -> &N {
+        self.nodes.last().expect("path should never be empty")
+    }
+}
+
+// Distances from the start nodes to every node reached, plus the parent links needed to
+// reconstruct the shortest paths
+#[derive(Debug, Clone)]
+pub struct SearchTree<N, C> {
+    distances: HashMap<N, C>,
+    parents: HashMap<N, N>,
+}
+
+impl<N: Clone + Eq + Hash, C: Copy> SearchTree<N, C> {
+    fn new() -> Self {
+        Self {
+            distances: HashMap::new(),
+            parents: HashMap::new(),
+        }
+    }
+
+    pub fn distance(&self, node: &N) -> Option<C> {
+        self.distances.get(node).copied()
+    }
+
+    pub fn distances(&self) -> &HashMap<N, C> {
+        &self.distances
+    }
+
+    pub fn contains(&self, node: &N) -> bool {
+        self.distances.contains_key(node)
+    }
+
+    // Returns None if the node was not reached
+    pub fn path_to(&self, node: &N) -> Option<Path<N, C>> {
+        let cost = self.distance(node)?;
+
+        let mut nodes = vec![node.clone()];
+        while let Some(parent) = self.parents.get(nodes.last().unwrap()) {
+            nodes.push(parent.clone());
+        }
+        nodes.reverse();
+
+        Some(Path { cost, nodes })
+    }
+}
+
+// Explore everything reachable from the start nodes, where every edge has cost 1
+pub fn bfs<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    neighbours: impl FnMut(&N) -> I,
+) -> SearchTree<N, usize>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    bfs_until(starts, neighbours, |_| false).0
+}
+
+// Shortest path from any of the start nodes to the nearest goal, where every edge has cost 1
+pub fn bfs_to<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    neighbours: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, usize>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let (tree, goal) = bfs_until(starts, neighbours, is_goal);
+    tree.path_to(&goal?)
+}
+
+fn bfs_until<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> (SearchTree<N, usize>, Option<N>)
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut tree = SearchTree::new();
+    let mut queue: VecDeque<N> = VecDeque::new();
+    for start in starts {
+        if tree.distances.insert(start.clone(), 0).is_none() {
+            queue.push_back(start);
+        }
+    }
+
+    while let Some(node) = queue.pop_front() {
+        if is_goal(&node) {
+            return (tree, Some(node));
+        }
+
+        let distance = tree.distances[&node];
+        for next in neighbours(&node) {
+            if let Entry::Vacant(entry) = tree.distances.entry(next.clone()) {
+                entry.insert(distance + 1);
+                tree.parents.insert(next.clone(), node.clone());
+                queue.push_back(next);
+            }
+        }
+    }
+
+    (tree, None)
+}
+
+// Explore everything reachable from the start nodes using non-negative edge costs
+pub fn dijkstra<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    neighbours: impl FnMut(&N) -> I,
+) -> SearchTree<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+{
+    best_first(starts, neighbours, |_| C::default(), |_| false).0
+}
+
+// Cheapest path from any of the start nodes to a goal using non-negative edge costs
+pub fn dijkstra_to<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    neighbours: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, C>>
+where
+    N: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let (tree, goal) = best_first(starts, neighbours, |_| C::default(), is_goal);
+    tree.path_to(&goal?)
+}
+
+// Cheapest path from start to a goal. The heuristic must never overestimate the remaining cost
+// to the nearest goal, or the path returned may not be the cheapest.
+pub fn astar<N, C, I>(
+    start: N,
+    neighbours: impl FnMut(&N) -> I,
+    heuristic: impl FnMut(&N) -> C,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, C>>
+where
+    N: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let (tree, goal) = best_first([start], neighbours, heuristic, is_goal);
+    tree.path_to(&goal?)
+}
+
+fn best_first<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> (SearchTree<N, C>, Option<N>)
+where
+    N: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut tree = SearchTree::new();
+
+    // Nodes aren't required to be Ord, so the heap refers to them by index into this list
+    let mut queued_nodes: Vec<N> = Vec::new();
+    let mut heap: BinaryHeap<Reverse<(C, C, usize)>> = BinaryHeap::new();
+    for start in starts {
+        let estimate = heuristic(&start);
+        tree.distances.insert(start.clone(), C::default());
+        heap.push(Reverse((estimate, C::default(), queued_nodes.len())));
+        queued_nodes.push(start);
+    }
+
+    // There is no closed set: a node is queued again whenever a cheaper path to it turns up, even
+    // after it was expanded, which an admissible heuristic that is not consistent can cause
+    while let Some(Reverse((_, distance, index))) = heap.pop() {
+        let node = queued_nodes[index].clone();
+        if distance > tree.distances[&node] {
+            // Stale entry for a node that was since reached more cheaply
+            continue;
+        }
+
+        if is_goal(&node) {
+            return (tree, Some(node));
+        }
+
+        for (next, cost) in neighbours(&node) {
+            let next_distance = distance + cost;
+            if tree
+                .distance(&next)
+                .is_some_and(|existing| existing <= next_distance)
+            {
+                continue;
+            }
+
+            tree.distances.insert(next.clone(), next_distance);
+            tree.parents.insert(next.clone(), node.clone());
+            heap.push(Reverse((
+                next_distance + heuristic(&next),
+                next_distance,
+                queued_nodes.len(),
+            )));
+            queued_nodes.push(next);
+        }
+    }
+
+    (tree, None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Edges of a small weighted graph, where the direct edge 0 -> 3 is more expensive than the
+    // path through 1 and 2
+    fn weighted_neighbours(node: &u32) -> Vec<(u32, u32)> {
+        match node {
+            0 => vec![(1, 1), (3, 10)],
+            1 => vec![(2, 2)],
+            2 => vec![(3, 3)],
+            3 => vec![(4, 1)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn test_bfs() {
+        // Number line where each node can step forward by 1 or 3
+        let neighbours = |&n: &u32| [n + 1, n + 3].into_iter().filter(|&n| n <= 10);
+
+        let path = bfs_to([0], neighbours, |&n| n == 9).unwrap();
+        assert_eq!(3, path.cost);
+        assert_eq!(vec![0, 3, 6, 9], path.nodes);
+
+        let tree = bfs([0], neighbours);
+        assert_eq!(11, tree.distances().len());
+        assert_eq!(Some(4), tree.distance(&10));
+
+        assert_eq!(None, bfs_to([0], neighbours, |&n| n == 11));
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let path = dijkstra_to([0], weighted_neighbours, |&n| n == 4).unwrap();
+        assert_eq!(7, path.cost);
+        assert_eq!(vec![0, 1, 2, 3, 4], path.nodes);
+
+        let tree = dijkstra([0], weighted_neighbours);
+        assert_eq!(Some(6), tree.distance(&3));
+        assert_eq!(Some(vec![0, 1, 2]), tree.path_to(&2).map(|path| path.nodes));
+        assert!(!tree.contains(&5));
+    }
+
+    #[test]
+    fn test_astar() {
+        // Grid walk from (0, 0) to (4, 4) around a wall at x = 2 that is open only at y = 4
+        let neighbours = |&(x, y): &(i32, i32)| {
+            [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)]
+                .into_iter()
+                .filter(|&(x, y)| (0..5).contains(&x) && (0..5).contains(&y))
+                .filter(|&(x, y)| x != 2 || y == 4)
+                .map(|node| (node, 1))
+        };
+        let heuristic = |&(x, y): &(i32, i32)| (4 - x).abs() + (4 - y).abs();
+
+        let path = astar((0, 0), neighbours, heuristic, |&node| node == (4, 4)).unwrap();
+        assert_eq!(8, path.cost);
+        assert_eq!(&(0, 0), path.start());
+        assert_eq!(&(4, 4), path.goal());
+        assert!(path.nodes.contains(&(2, 4)));
+    }
+
+    #[test]
+    fn test_astar_inconsistent_heuristic() {
+        // The heuristic is exact at 'a' but zero at 'c', so 'c' is first expanded by way of the
+        // more expensive 'b' and has to be expanded again once the cheaper path through 'a' is found
+        let neighbours = |node: &char| match node {
+            's' => vec![('a', 1), ('b', 1)],
+            'a' => vec![('c', 1)],
+            'b' => vec![('c', 2)],
+            'c' => vec![('g', 5)],
+            _ => vec![],
+        };
+        let heuristic = |node: &char| if *node == 'a' { 6 } else { 0 };
+
+        let path = astar('s', neighbours, heuristic, |&node| node == 'g').unwrap();
+        assert_eq!(7, path.cost);
+        assert_eq!(vec!['s', 'a', 'c', 'g'], path.nodes);
+    }
+}
@@ -0,0 +1,217 @@
+use std::collections::BTreeMap;
+use std::fmt::{self, Display};
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+use crate::error::ParseError;
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Self; 2] = [Self::One, Self::Two];
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Self::One),
+            "2" => Ok(Self::Two),
+            _ => Err(format!("part should be 1 or 2, was {s}")),
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::One => write!(f, "1"),
+            Self::Two => write!(f, "2"),
+        }
+    }
+}
+
+// Day-specific settings given on the command line as name=value
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Options {
+    values: BTreeMap<String, String>,
+}
+
+impl Options {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(&mut self, name: &str, value: &str) {
+        self.values.insert(String::from(name), String::from(value));
+    }
+
+    // Add an option from an argument of the form name=value
+    pub fn insert_arg(&mut self, arg: &str) -> Result<(), String> {
+        match arg.split_once('=') {
+            Some((name, value)) if !name.is_empty() => {
+                self.insert(name, value);
+                Ok(())
+            }
+            _ => Err(format!(
+                "option should be in the format name=value, was {arg}"
+            )),
+        }
+    }
+
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.values.get(name).map(String::as_str)
+    }
+
+    pub fn names(&self) -> impl Iterator<Item = &str> + '_ {
+        self.values.keys().map(String::as_str)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+}
+
+// A single day's puzzle, split into parsing the input and solving each part from the parsed input
+pub trait Solution {
+    const DAY: u32;
+
+    // Day 25 only has one part
+    const HAS_PART_2: bool = true;
+
+    type Input;
+    type Output1: Display;
+    type Output2: Display;
+
+    // Names of the options that configure accepts
+    const OPTIONS: &'static [&'static str] = &[];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    // Apply day-specific options to the parsed input; only called with names listed in OPTIONS
+    fn configure(_input: &mut Self::Input, _options: &Options) -> Result<(), String> {
+        Ok(())
+    }
+
+    fn part1(input: &Self::Input) -> Self::Output1;
+
+    fn part2(input: &Self::Input) -> Self::Output2;
+}
+
+#[derive(Debug, Clone)]
+pub struct PartResult {
+    pub part: Part,
+    pub answer: String,
+    pub elapsed: Duration,
+}
+
+// The answers for one day's requested parts along with how long each step took
+#[derive(Debug, Clone)]
+pub struct DayResult {
+    pub parse_elapsed: Duration,
+    pub parts: Vec<PartResult>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum SolveError {
+    Parse(ParseError),
+    InvalidOption(String),
+}
+
+impl Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Parse(err) => write!(f, "{err}"),
+            Self::InvalidOption(message) => write!(f, "{message}"),
+        }
+    }
+}
+
+// Object-safe wrapper around Solution so that every day can be registered in one list
+pub trait Solver {
+    fn day(&self) -> u32;
+
+    fn parts(&self) -> &'static [Part];
+
+    fn options(&self) -> &'static [&'static str];
+
+    // Parse the input once and then solve the requested parts, returning the answers as strings
+    fn solve(
+        &self,
+        input: &str,
+        parts: &[Part],
+        options: &Options,
+    ) -> Result<DayResult, SolveError>;
+}
+
+impl<S: Solution> Solver for S {
+    fn day(&self) -> u32 {
+        S::DAY
+    }
+
+    fn parts(&self) -> &'static [Part] {
+        if S::HAS_PART_2 {
+            &Part::ALL
+        } else {
+            &Part::ALL[..1]
+        }
+    }
+
+    fn options(&self) -> &'static [&'static str] {
+        S::OPTIONS
+    }
+
+    fn solve(
+        &self,
+        input: &str,
+        parts: &[Part],
+        options: &Options,
+    ) -> Result<DayResult, SolveError> {
+        if let Some(name) = options.names().find(|name| !S::OPTIONS.contains(name)) {
+            let message = match S::OPTIONS {
+                [] => format!("day {} does not take any options, got {name}", S::DAY),
+                known => format!(
+                    "unknown option {name} for day {}; expected one of {}",
+                    S::DAY,
+                    known.join(", ")
+                ),
+            };
+            return Err(SolveError::InvalidOption(message));
+        }
+
+        let start = Instant::now();
+        let mut input = S::parse(input).map_err(|err| SolveError::Parse(err.for_day(S::DAY)))?;
+        let parse_elapsed = start.elapsed();
+
+        S::configure(&mut input, options).map_err(|message| {
+            SolveError::InvalidOption(format!("invalid option for day {}: {message}", S::DAY))
+        })?;
+
+        let parts = parts
+            .iter()
+            .filter(|part| self.parts().contains(part))
+            .map(|&part| {
+                let start = Instant::now();
+                let answer = match part {
+                    Part::One => S::part1(&input).to_string(),
+                    Part::Two => S::part2(&input).to_string(),
+                };
+                PartResult {
+                    part,
+                    answer,
+                    elapsed: start.elapsed(),
+                }
+            })
+            .collect();
+
+        Ok(DayResult {
+            parse_elapsed,
+            parts,
+        })
+    }
+}